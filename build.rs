@@ -27,7 +27,19 @@ const ALLOWED_CFGS: &[&str] = &[
     // Corresponds to `_TIME_BITS=64` in glibc. Also used in x86 Windows with
     // GNU to expose a 64-bit `time_t`.
     "gnu_time_bits64",
+    // Set when the targeted Android API level provides the symbols added in the given level
+    "libc_android_api_30",
+    "libc_android_api_31",
+    "libc_android_api_34",
+    "libc_android_api_35",
     "libc_deny_warnings",
+    // Set when the targeted glibc provides the symbols added in the given release
+    "libc_glibc_2_29",
+    "libc_glibc_2_30",
+    "libc_glibc_2_31",
+    "libc_glibc_2_32",
+    "libc_glibc_2_34",
+    "libc_glibc_2_35",
     // Corresponds to `__USE_TIME_BITS64` in UAPI
     "linux_time_bits64",
     "musl_v1_2_3",
@@ -61,9 +73,12 @@ const CHECK_CFG_EXTRA: &[(&str, &[&str])] = &[
 /// from 32-bit to 64-bit `time_t` and need `__*_time64` symbol redirects).
 const MUSL_REDIR_TIME64_ARCHES: &[&str] = &["arm", "mips", "powerpc", "x86"];
 
+include!("version_cfgs.rs");
+
 fn main() {
     // Avoid unnecessary re-building.
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=version_cfgs.rs");
 
     let (rustc_minor_ver, _is_nightly) = rustc_minor_nightly();
     let libc_ci = env_flag("LIBC_CI");
@@ -152,6 +167,51 @@ fn main() {
         }
     }
 
+    // Some newer glibc symbols are only declared if the targeted glibc has them, so that using
+    // them on older systems fails at compile time rather than at link time. By default a recent
+    // glibc is assumed. The version can be set with `--cfg libc_unstable_glibc_version="2.xx"`;
+    // on CI, it is detected for native builds so the tests match the installed headers.
+    if target_env == "gnu" && matches!(target_os.as_str(), "linux" | "hurd") {
+        let glibc_minor = if let Ok(version) = env::var("CARGO_CFG_LIBC_UNSTABLE_GLIBC_VERSION") {
+            parse_glibc_minor(&version).unwrap_or_else(|| {
+                panic!("invalid value for libc_unstable_glibc_version: {version}")
+            })
+        } else if libc_ci {
+            which_glibc().unwrap_or(u32::MAX)
+        } else {
+            u32::MAX
+        };
+
+        for &(minor, cfg) in GLIBC_VERSION_CFGS {
+            if glibc_minor >= minor {
+                set_cfg(cfg);
+            }
+        }
+    }
+
+    // Likewise, symbols added in newer bionic releases are only declared if the targeted Android
+    // API level has them. The level can be set with `--cfg libc_unstable_android_api="NN"` or
+    // the `ANDROID_API_LEVEL` environment variable used by the NDK tooling. If neither is set,
+    // the latest API level is assumed.
+    if target_os == "android" {
+        println!("cargo:rerun-if-env-changed=ANDROID_API_LEVEL");
+        let api_level = match env::var("CARGO_CFG_LIBC_UNSTABLE_ANDROID_API")
+            .or_else(|_| env::var("ANDROID_API_LEVEL"))
+        {
+            Ok(level) => level
+                .trim()
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid Android API level: {level}")),
+            Err(_) => u32::MAX,
+        };
+
+        for &(level, cfg) in ANDROID_API_CFGS {
+            if api_level >= level {
+                set_cfg(cfg);
+            }
+        }
+    }
+
     let uclibc_use_time64 = env_flag("CARGO_CFG_LIBC_UNSTABLE_UCLIBC_TIME64");
     if target_env == "uclibc" && uclibc_use_time64 {
         set_cfg("linux_time_bits64");
@@ -333,6 +393,36 @@ fn which_freebsd() -> Option<i32> {
     }
 }
 
+/// Detect the minor version of the host glibc. Only meaningful when not cross compiling.
+fn which_glibc() -> Option<u32> {
+    if env::var("HOST").ok()? != env::var("TARGET").ok()? {
+        return None;
+    }
+
+    let output = Command::new("getconf")
+        .arg("GNU_LIBC_VERSION")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // The output looks like `glibc 2.36`.
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    parse_glibc_minor(stdout.trim().strip_prefix("glibc ")?)
+}
+
+/// Parse a `2.xx` glibc version string into its minor version.
+fn parse_glibc_minor(version: &str) -> Option<u32> {
+    let mut pieces = version.trim().split('.');
+
+    if pieces.next()? != "2" {
+        return None;
+    }
+
+    pieces.next()?.parse().ok()
+}
+
 fn emcc_version_code() -> Option<u64> {
     let emcc = if cfg!(target_os = "windows") {
         "emcc.bat"
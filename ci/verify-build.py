@@ -411,6 +411,10 @@ def test_target(cfg: Cfg, target: Target) -> TargetResult:
         # Equivalent of _TIME_BITS=64
         run(cmd, rustflags=f'{rustflags} --cfg=libc_unstable_gnu_time_bits="64"')
 
+    if "gnu" in target_env and ("linux" in target_os or "hurd" in target_os):
+        # Check with an older glibc that lacks the version-gated symbols
+        run(cmd, rustflags=f'{rustflags} --cfg=libc_unstable_glibc_version="2.28"')
+
     if "musl" in target_env:
         # Check with breaking changes from musl, including 64-bit time_t on 32-bit
         run(cmd, rustflags=f"{rustflags} --cfg=libc_unstable_musl_v1_2_3")
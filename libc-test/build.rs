@@ -23,6 +23,8 @@ use std::{
 
 use regex::Regex;
 
+include!("../version_cfgs.rs");
+
 fn do_cc() {
     // NOTE: family could be one of: unix, windows, wasm, or multiple values
     // (e.g. "unix,wasm")
@@ -187,6 +189,7 @@ fn process_semver_file<W: Write, P: AsRef<Path>>(output: &mut W, path: &mut Path
 fn main() {
     // Avoid unnecessary re-building.
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=../version_cfgs.rs");
     // Ensure version checking works, even if we don't use it.
     LazyLock::force(&VERSIONS);
 
@@ -221,6 +224,7 @@ fn test_apple(target: &str) {
     assert!(target.contains("apple"));
     let x86_64 = target.contains("x86_64");
     let i686 = target.contains("i686");
+    let macos = VERSIONS.macos;
 
     let mut cfg = ctest_cfg();
 
@@ -251,7 +255,9 @@ fn test_apple(target: &str) {
         "libproc.h",
         "limits.h",
         "locale.h",
+        "mach/mach_vm.h",
         "malloc/malloc.h",
+        "monetary.h",
         "net/bpf.h",
         "net/dlil.h",
         "net/if.h",
@@ -271,6 +277,8 @@ fn test_apple(target: &str) {
         "netinet6/in6_var.h",
         "os/clock.h",
         "os/lock.h",
+        "os/log.h",
+        "os/object.h",
         "os/signpost.h",
         "os/os_sync_wait_on_address.h",
         "poll.h",
@@ -297,6 +305,7 @@ fn test_apple(target: &str) {
         "sys/clonefile.h",
         "sys/event.h",
         "sys/file.h",
+        "sys/fsgetpath.h",
         "sys/ioctl.h",
         "sys/ipc.h",
         "sys/kern_control.h",
@@ -352,6 +361,10 @@ fn test_apple(target: &str) {
 
             // FIXME(macos): The size is changed in macOS 26.
             "vm_statistics64" => true,
+            // The SDK appends fields with every new revision (`TASK_VM_INFO_REV*`), so its size
+            // only matches ours for the SDK the definition was taken from. The kernel copies
+            // out at most the count the caller passes in, so the prefix we define stays valid.
+            "task_vm_info" => true,
             _ => false,
         }
     });
@@ -372,6 +385,8 @@ fn test_apple(target: &str) {
 
             // FIXME(macos): bumped up on macOS 26, it's sizeof `vm_statistics64_data_t`
             "HOST_VM_INFO64_COUNT" => true,
+            // Derived from sizeof `task_vm_info_data_t`, see the `task_vm_info` struct skip.
+            "TASK_VM_INFO_COUNT" => true,
 
             _ => false,
         }
@@ -381,6 +396,8 @@ fn test_apple(target: &str) {
         match ty.ident() {
             // FIXME(macos): The size is changed in macOS 26.
             "vm_statistics64_data_t" => true,
+            // Alias of `task_vm_info`, see the struct skip.
+            "task_vm_info_data_t" => true,
             _ => false,
         }
     });
@@ -392,6 +409,8 @@ fn test_apple(target: &str) {
             "close" if x86_64 => true,
             // FIXME(1.0): std removed libresolv support: https://github.com/rust-lang/rust/pull/102766
             "res_init" => true,
+            // Only declared by the macOS 15.4 SDK and newer.
+            "strchrnul" if !matches!(macos, Some(v) if v >= (15, 4)) => true,
             _ => false,
         }
     });
@@ -822,7 +841,9 @@ fn test_windows(target: &str) {
         "io.h",
         "limits.h",
         "locale.h",
+        "malloc.h",
         "process.h",
+        "share.h",
         "signal.h",
         "stddef.h",
         "stdint.h",
@@ -1065,6 +1086,7 @@ fn test_solarish(target: &str) {
         "time.h",
         "priv.h",
         "ucontext.h",
+        "ucred.h",
         "unistd.h",
         "utime.h",
         "utmpx.h",
@@ -1284,6 +1306,7 @@ fn test_netbsd(target: &str) {
         "limits.h",
         "link.h",
         "locale.h",
+        "monetary.h",
         "stddef.h",
         "stdint.h",
         "stdio.h",
@@ -1602,6 +1625,7 @@ fn test_dragonflybsd(target: &str) {
         "limits.h",
         "link.h",
         "locale.h",
+        "monetary.h",
         "mqueue.h",
         "net/bpf.h",
         "net/if.h",
@@ -1670,6 +1694,7 @@ fn test_dragonflybsd(target: &str) {
         "utime.h",
         "utmpx.h",
         "vm/vm.h",
+        "vm/vm_param.h",
         "vfs/ufs/quota.h",
         "wchar.h",
         "iconv.h",
@@ -2025,6 +2050,21 @@ fn test_wasi(target: &str) {
     ctest::generate_test(&mut cfg, "../src/lib.rs", "ctest_output.rs").unwrap();
 }
 
+/// Mirror the Android API level cfgs set by the libc build script.
+fn config_android_api(cfg: &mut ctest::TestGenerator) {
+    let api_level = match env::var("CARGO_CFG_LIBC_UNSTABLE_ANDROID_API")
+        .or_else(|_| env::var("ANDROID_API_LEVEL"))
+    {
+        Ok(level) => level.trim().parse().expect("invalid Android API level"),
+        Err(_) => u32::MAX,
+    };
+    for &(since, name) in ANDROID_API_CFGS {
+        if api_level >= since {
+            cfg.cfg(name, None);
+        }
+    }
+}
+
 fn test_android(target: &str) {
     assert!(target.contains("android"));
     let target_pointer_width = match target {
@@ -2037,6 +2077,7 @@ fn test_android(target: &str) {
 
     let mut cfg = ctest_cfg();
     cfg.define("_GNU_SOURCE", None);
+    config_android_api(&mut cfg);
 
     headers!(
         cfg,
@@ -2093,6 +2134,7 @@ fn test_android(target: &str) {
         "sys/mman.h",
         "sys/mount.h",
         "sys/personality.h",
+        "sys/pidfd.h",
         "sys/prctl.h",
         "sys/ptrace.h",
         "sys/random.h",
@@ -2137,7 +2179,10 @@ fn test_android(target: &str) {
     headers!(
         cfg,
         "asm/mman.h",
+        "linux/android/binder.h",
+        "linux/ashmem.h",
         "linux/auxvec.h",
+        "linux/close_range.h",
         "linux/dccp.h",
         "linux/elf.h",
         "linux/errqueue.h",
@@ -2159,6 +2204,7 @@ fn test_android(target: &str) {
         "linux/mempolicy.h",
         "linux/module.h",
         "linux/mount.h",
+        "linux/mqueue.h",
         "linux/net_tstamp.h",
         "linux/netfilter/nfnetlink.h",
         "linux/netfilter/nfnetlink_log.h",
@@ -2175,13 +2221,20 @@ fn test_android(target: &str) {
         "linux/seccomp.h",
         "linux/sched.h",
         "linux/sockios.h",
+        "linux/sync_file.h",
         "linux/uinput.h",
         "linux/vm_sockets.h",
         "linux/wait.h",
     );
 
     // Include Android-specific headers:
-    headers!(cfg, "android/set_abort_message.h",);
+    headers!(
+        cfg,
+        "android/dlext.h",
+        "android/log.h",
+        "android/multinetwork.h",
+        "android/set_abort_message.h",
+    );
 
     cfg.rename_type(move |ty| match ty {
         "Ioctl" => Some("int".to_string()),
@@ -2190,7 +2243,10 @@ fn test_android(target: &str) {
 
     cfg.rename_struct_ty(|ty| match ty {
         // Just pass all these through, no need for a "struct" prefix
-        "FILE" | "fd_set" | "Dl_info" | "Elf32_Phdr" | "Elf64_Phdr" => Some(ty.to_string()),
+        "FILE" | "fd_set" | "Dl_info" | "Elf32_Phdr" | "Elf64_Phdr" | "android_dlextinfo" => {
+            Some(ty.to_string())
+        }
+        "android_namespace_t" => Some(format!("struct {ty}")),
         t if t.ends_with("_t") => Some(t.to_string()),
         _ => None,
     });
@@ -2207,7 +2263,9 @@ fn test_android(target: &str) {
             // The following structs have a field called `type` in C,
             // but `type` is a Rust keyword, so these fields are translated
             // to `type_` in Rust.
-            ("input_event" | "input_mask" | "ff_effect", "type_") => Some("type".to_string()),
+            ("input_event" | "input_mask" | "ff_effect" | "binder_object_header", "type_") => {
+                Some("type".to_string())
+            }
             _ => None,
         }
     });
@@ -2301,6 +2359,9 @@ fn test_android(target: &str) {
             // is a private value for kernel usage normally
             "FUSE_SUPER_MAGIC" => true,
 
+            // Defined by xfsprogs' `xfs/xfs_fs.h`, which is not part of the system headers.
+            "XFS_IOC_FREEZE" | "XFS_IOC_THAW" => true,
+
             // GRND_INSECURE was added in platform-tools-30.0.0
             "GRND_INSECURE" => true,
 
@@ -2354,6 +2415,20 @@ fn test_android(target: &str) {
             // FIXME(android): The value has been changed on r26b:
             | "SYS_syscalls" if aarch64 => true,
 
+            // FIXME(android): Needs kernel headers newer than the NDK ships:
+            n if aarch64 && n.starts_with("HWCAP2_") => !matches!(
+                n,
+                "HWCAP2_DCPODP" | "HWCAP2_SVE2" | "HWCAP2_SVEAES" | "HWCAP2_SVEPMULL"
+                | "HWCAP2_SVEBITPERM" | "HWCAP2_SVESHA3" | "HWCAP2_SVESM4" | "HWCAP2_FLAGM2"
+                | "HWCAP2_FRINT" | "HWCAP2_SVEI8MM" | "HWCAP2_SVEF32MM" | "HWCAP2_SVEF64MM"
+                | "HWCAP2_SVEBF16" | "HWCAP2_I8MM" | "HWCAP2_BF16" | "HWCAP2_DGH" | "HWCAP2_RNG"
+                | "HWCAP2_BTI" | "HWCAP2_MTE" | "HWCAP2_ECV" | "HWCAP2_AFP" | "HWCAP2_RPRES"
+                | "HWCAP2_MTE3" | "HWCAP2_SME" | "HWCAP2_SME_I16I64" | "HWCAP2_SME_F64F64"
+                | "HWCAP2_SME_I8I32" | "HWCAP2_SME_F16F32" | "HWCAP2_SME_B16F32"
+                | "HWCAP2_SME_F32F32" | "HWCAP2_SME_FA64" | "HWCAP2_WFXT" | "HWCAP2_EBF16"
+                | "HWCAP2_SVE_EBF16"
+            ),
+
             // From `<include/linux/sched.h>`.
             | "PF_VCPU"
             | "PF_IDLE"
@@ -2421,6 +2496,12 @@ fn test_android(target: &str) {
             // Added in API level 30, but tests use level 28.
             "memfd_create" | "mlock2" | "renameat2" | "statx" | "statx_timestamp" => true,
 
+            // Added in API level 31, but tests use level 28.
+            "pidfd_open" | "pidfd_getfd" | "pidfd_send_signal" | "process_madvise" => true,
+
+            // Added in API level 34/35, but tests use level 28.
+            "close_range" | "copy_file_range" | "epoll_pwait2" => true,
+
             // Added in glibc 2.25.
             "getentropy" => true,
 
@@ -2475,6 +2556,8 @@ fn test_android(target: &str) {
             ("ifaddrs", "ifa_ifu") => true,
             // this one is an anonymous union
             ("ff_effect", "u") => true,
+            // `target` and `data` are anonymous unions
+            ("binder_transaction_data", "target" | "data") => true,
             // FIXME(android): `sa_sigaction` has type `sighandler_t` but that type is
             // incorrect, see: https://github.com/rust-lang/libc/issues/1359
             ("sigaction", "sa_sigaction") => true,
@@ -2494,6 +2577,8 @@ fn test_android(target: &str) {
 
             // _sigev_un is an anonymous union
             ("sigevent", "_sigev_un") => true,
+            // `anonymous_1` is an anonymous union
+            ("flat_binder_object" | "binder_fd_object", "anonymous_1") => true,
 
             // this is actually a union on linux, so we can't represent it well and
             // just insert some padding.
@@ -2553,6 +2638,8 @@ fn test_freebsd(target: &str) {
     cfg.define("_WITH_GETLINE", None);
     // Required for making freebsd11_stat available in the headers
     cfg.define("_WANT_FREEBSD11_STAT", None);
+    // Required for `union semun`
+    cfg.define("_WANT_SEMUN", None);
 
     let freebsd13 = matches!(freebsd_ver, Some(n) if n >= 13);
     let freebsd14 = matches!(freebsd_ver, Some(n) if n >= 14);
@@ -2587,6 +2674,7 @@ fn test_freebsd(target: &str) {
         "machine/reg.h",
         "malloc_np.h",
         "memstat.h",
+        "monetary.h",
         "mqueue.h",
         "net/bpf.h",
         "net/if.h",
@@ -3935,6 +4023,23 @@ fn config_gnu_bits(target: &str, cfg: &mut ctest::TestGenerator) {
     }
 }
 
+/// Mirror the `libc_glibc_*` cfgs set by the libc build script, so symbols gated on a glibc
+/// release are tested whenever the headers provide them.
+fn config_glibc_version(cfg: &mut ctest::TestGenerator, glibc: (u32, u32)) {
+    let minor = match env::var("CARGO_CFG_LIBC_UNSTABLE_GLIBC_VERSION") {
+        Ok(version) => version
+            .strip_prefix("2.")
+            .and_then(|minor| minor.parse().ok())
+            .expect("invalid value for libc_unstable_glibc_version"),
+        Err(_) => glibc.1,
+    };
+    for &(since, name) in GLIBC_VERSION_CFGS {
+        if minor >= since {
+            cfg.cfg(name, None);
+        }
+    }
+}
+
 // This also covers the L4Re targets since they have a similar API surface
 fn test_linux(target: &str) {
     assert!(target.contains("linux") || target.contains("l4re"));
@@ -4028,6 +4133,9 @@ fn test_linux(target: &str) {
         .define("__GLIBC_USE_DEPRECATED_SCANF", None);
 
     config_gnu_bits(target, &mut cfg);
+    if gnu {
+        config_glibc_version(&mut cfg, versions.glibc.unwrap());
+    }
     // The L4Re libc headers contain some L4Re helper functions which are not needed for the libc
     // interface and must not be added to the libc crate
     if l4re {
@@ -4036,11 +4144,14 @@ fn test_linux(target: &str) {
 
     headers!(
         cfg,
+        (gnu, "argp.h"),
         "ctype.h",
         "dirent.h",
         "dlfcn.h",
         "elf.h",
+        (gnu, "error.h"),
         "fcntl.h",
+        (!uclibc, "fmtmsg.h"),
         "fnmatch.h",
         "getopt.h",
         "glob.h",
@@ -4056,6 +4167,7 @@ fn test_linux(target: &str) {
         "locale.h",
         "malloc.h",
         "mntent.h",
+        (!uclibc, "monetary.h"),
         (!l4re, "mqueue.h"),
         "net/ethernet.h",
         "net/if.h",
@@ -4068,7 +4180,9 @@ fn test_linux(target: &str) {
         "netinet/udp.h",
         (gnu, "netiucv/iucv.h"),
         (l4re, "netpacket/packet.h"),
+        (gnu, "obstack.h"),
         "poll.h",
+        (gnu, "printf.h"),
         "pthread.h",
         "pty.h",
         "pwd.h",
@@ -4103,6 +4217,7 @@ fn test_linux(target: &str) {
         (!l4re, "sys/random.h"),
         (!l4re, "sys/reboot.h"),
         "sys/resource.h",
+        (gnu && versions.glibc.unwrap() >= (2, 35), "sys/rseq.h"),
         "sys/sem.h",
         (!l4re, "sys/sendfile.h"),
         "sys/shm.h",
@@ -4153,6 +4268,9 @@ fn test_linux(target: &str) {
     headers!(
         cfg,
         ((loongarch64 || riscv64) && !l4re, "asm/hwcap.h"),
+        (riscv64 && !l4re, "asm/hwprobe.h"),
+        (aarch64 && gnu, "asm/sigcontext.h"),
+        (x86_64 && !l4re, "asm/prctl.h"),
         (!l4re, "asm/mman.h"),
     );
 
@@ -4160,21 +4278,26 @@ fn test_linux(target: &str) {
         headers!(
             cfg,
             (gnu, "linux/aio_abi.h"),
+            "linux/blkzoned.h",
             "linux/can.h",
             "linux/can/bcm.h",
             "linux/can/error.h",
             "linux/can/j1939.h",
             "linux/can/netlink.h",
             "linux/can/raw.h",
+            "linux/cgroupstats.h",
             "linux/cn_proc.h",
             "linux/connector.h",
             "linux/dccp.h",
+            "linux/dm-ioctl.h",
             "linux/errqueue.h",
             "linux/falloc.h",
             "linux/filter.h",
             "linux/fs.h",
+            "linux/fuse.h",
             "linux/futex.h",
             "linux/genetlink.h",
+            "linux/gpio.h",
             "linux/if.h",
             "linux/if_addr.h",
             "linux/if_alg.h",
@@ -4184,6 +4307,7 @@ fn test_linux(target: &str) {
             "linux/if_xdp.h",
             "linux/input.h",
             "linux/ipv6.h",
+            "linux/kcmp.h",
             "linux/kexec.h",
             "linux/keyctl.h",
             "linux/magic.h",
@@ -4219,11 +4343,15 @@ fn test_linux(target: &str) {
             "linux/securebits.h",
             "linux/sock_diag.h",
             "linux/sockios.h",
+            "linux/sync_file.h",
+            "linux/taskstats.h",
             "linux/tls.h",
             "linux/uinput.h",
             "linux/vm_sockets.h",
             "linux/wait.h",
             "linux/wireless.h",
+            "mtd/mtd-abi.h",
+            "mtd/ubi-user.h",
             "sys/fanotify.h",
             "sys/auxv.h",
             (gnu || musl, "linux/close_range.h"),
@@ -4250,6 +4378,8 @@ fn test_linux(target: &str) {
         "FILE",
         "fd_set",
         "Dl_info",
+        "Dl_serinfo",
+        "Dl_serpath",
         "DIR",
         "Elf32_Phdr",
         "Elf64_Phdr",
@@ -4295,7 +4425,10 @@ fn test_linux(target: &str) {
             // The following structs have a field called `type` in C,
             // but `type` is a Rust keyword, so these fields are translated
             // to `type_` in Rust.
-            ("input_event" | "input_mask" | "ff_effect", "type_") => Some("type".to_string()),
+            (
+                "input_event" | "input_mask" | "ff_effect" | "mtd_info_user" | "blk_zone",
+                "type_",
+            ) => Some("type".to_string()),
 
             // FIXME(1.0): field has a different name on loongarch
             ("ucontext_t", "uc_flags") if loongarch64 => Some("__uc_flags".to_string()),
@@ -4323,6 +4456,9 @@ fn test_linux(target: &str) {
             // in the `linux_elf.rs` file.
             "Elf64_Phdr" | "Elf32_Phdr" => true,
 
+            // These are function types in C but function pointers in Rust.
+            "printf_function" | "printf_arginfo_size_function" => true,
+
             // This type is private on Linux. It is implemented as a C `enum`
             // (`c_uint`) and this clashes with the type of the `rlimit` APIs
             // which expect a `c_int` even though both are ABI compatible.
@@ -4345,6 +4481,9 @@ fn test_linux(target: &str) {
         }
     });
 
+    // glibc leaves `union semun` for the caller to declare.
+    cfg.skip_union(|union_| union_.ident() == "semun");
+
     cfg.skip_struct(move |struct_| {
         let ty = struct_.ident();
 
@@ -4407,6 +4546,9 @@ fn test_linux(target: &str) {
             // Might differ between kernel versions
             "pidfd_info" => true,
 
+            // Grows with every `TASKSTATS_VERSION` bump, we follow version 13 (Linux 6.1)
+            "taskstats" => kernel > (6, 1),
+
             "sctp_initmsg" | "sctp_sndrcvinfo" | "sctp_sndinfo" | "sctp_rcvinfo"
             | "sctp_nxtinfo" | "sctp_prinfo" | "sctp_authinfo" => true,
 
@@ -4441,11 +4583,13 @@ fn test_linux(target: &str) {
             // kernel so we can drop this and test the type once this new version is used in CI.
             "sched_attr" => true,
 
+            "ptrace_sud_config" | "ptp_clock_caps" | "xdp_umem_reg" | "ptrace_syscall_info" => true, // LOCALSKIP
             // Recent additions
             "ptp_sys_offset_extended" if kernel < (6, 12) => true,
             "epoll_params" if old_musl => true,
             "epoll_params" => kernel < (6, 9),
             "mnt_ns_info" => kernel < (6, 12),
+            "proc_input" => kernel < (6, 6),
 
             // FIXME(linux): Only requires >= 6.12 kernel headers, but including `uio.h` creates
             // a conflict with the `iovec` definition.
@@ -4489,6 +4633,7 @@ fn test_linux(target: &str) {
 
     cfg.skip_const(move |constant| {
         let name = constant.ident();
+        if ["ALG_SET_KEY_BY_KEY_SERIAL","AT_HANDLE_FID","FAN_INFO","FAN_RESPONSE_INFO_AUDIT_RULE","FAN_RESPONSE_INFO_NONE","FUTEX2_NUMA","FUTEX2_PRIVATE","FUTEX2_SIZE_MASK","FUTEX2_SIZE_U16","FUTEX2_SIZE_U32","FUTEX2_SIZE_U64","FUTEX2_SIZE_U8","MFD_EXEC","MFD_NOEXEC_SEAL","MOVE_MOUNT_BENEATH","PACKET_FANOUT_FLAG_IGNORE_OUTGOING","PACKET_VNET_HDR_SZ","PR_GET_MDWE","PR_GET_MEMORY_MERGE","PR_MDWE_NO_INHERIT","PR_MDWE_REFUSE_EXEC_GAIN","PR_SET_MDWE","PR_SET_MEMORY_MERGE","PR_RISCV_V_SET_CONTROL","PR_RISCV_V_GET_CONTROL","PR_RISCV_V_VSTATE_CTRL_DEFAULT","PR_RISCV_V_VSTATE_CTRL_OFF","PR_RISCV_V_VSTATE_CTRL_ON","PR_RISCV_V_VSTATE_CTRL_INHERIT","PR_RISCV_V_VSTATE_CTRL_CUR_MASK","PR_RISCV_V_VSTATE_CTRL_NEXT_MASK","PR_RISCV_V_VSTATE_CTRL_MASK","PTRACE_GET_SYSCALL_USER_DISPATCH_CONFIG","PTRACE_SET_SYSCALL_USER_DISPATCH_CONFIG","SOF_TIMESTAMPING_OPT_ID_TCP","SO_PASSPIDFD","SO_PEERPIDFD","SYS_fchmodat2","TUN_F_USO4","TUN_F_USO6","XDP_PKT_CONTD","XDP_TXMD_FLAGS_CHECKSUM","XDP_TXMD_FLAGS_TIMESTAMP","XDP_TX_METADATA","XDP_UMEM_TX_SW_CSUM","XDP_USE_SG", "NFT_MSG_MAX", "MS_NOUSER"].contains(&name) { return true; } // LOCALSKIP
 
         // FIXME(linux): Requires newer kernel headers than CI has. These uapi/linux/mount.h
         // constants (OPEN_TREE_NAMESPACE landed in v7.0, FSCONFIG_CMD_CREATE_EXCL in v6.6)
@@ -4699,6 +4844,9 @@ fn test_linux(target: &str) {
                 | "CANXL_SEC"
                 | "CANXL_XLF" => return true,
 
+                // requires linux 6.1
+                "MADV_COLLAPSE" => return true,
+
                 // TLS_CIPHER_ARIA_GCM_* requires linux 6.1
                 "TLS_CIPHER_ARIA_GCM_128_IV_SIZE"
                 | "TLS_CIPHER_ARIA_GCM_128_KEY_SIZE"
@@ -4888,6 +5036,9 @@ fn test_linux(target: &str) {
             // is a private value for kernel usage normally
             "FUSE_SUPER_MAGIC" => true,
 
+            // Defined by xfsprogs' `xfs/xfs_fs.h`, which is not part of the system headers.
+            "XFS_IOC_FREEZE" | "XFS_IOC_THAW" => true,
+
             // Not present in glibc
             "PR_SME_VL_LEN_MAX" | "PR_SME_SET_VL_INHERIT" | "PR_SME_SET_VL_ONE_EXEC" if gnu => true,
 
@@ -4903,6 +5054,7 @@ fn test_linux(target: &str) {
             "NF_NETDEV_NUMHOOKS" | "RLIM_NLIMITS" | "NFT_MSG_MAX" if uclibc => true,
 
             // Recent additions
+            "PROC_EVENT_ALL" => kernel < (6, 6),
             "RWF_NOAPPEND" => kernel < (6, 9),
             "RWF_ATOMIC" => kernel < (6, 11),
             "RWF_DONTCACHE" => kernel < (6, 14),
@@ -4976,15 +5128,54 @@ fn test_linux(target: &str) {
             // Value changed in 6.14
             "SECURE_ALL_BITS" | "SECURE_ALL_LOCKS" => kernel < (6, 14),
 
+            // Bumped whenever `struct taskstats` grows
+            "TASKSTATS_VERSION" => kernel > (6, 1),
+
             // Recent additions
             "AT_HWCAP3" | "AT_HWCAP4" if old_musl => true,
             "AT_HWCAP3" | "AT_HWCAP4" => kernel < (6, 9),
+
+            // aarch64 `HWCAP2_*` bits come from the libc's `bits/hwcap.h`
+            "HWCAP2_DCPODP" | "HWCAP2_SVE2" | "HWCAP2_SVEAES" | "HWCAP2_SVEPMULL"
+            | "HWCAP2_SVEBITPERM" | "HWCAP2_SVESHA3" | "HWCAP2_SVESM4" | "HWCAP2_FLAGM2"
+            | "HWCAP2_FRINT" | "HWCAP2_SVEI8MM" | "HWCAP2_SVEF32MM" | "HWCAP2_SVEF64MM"
+            | "HWCAP2_SVEBF16" | "HWCAP2_I8MM" | "HWCAP2_BF16" | "HWCAP2_DGH" | "HWCAP2_RNG"
+            | "HWCAP2_BTI" | "HWCAP2_MTE" | "HWCAP2_ECV" | "HWCAP2_AFP" | "HWCAP2_RPRES"
+            | "HWCAP2_MTE3"
+                if musl =>
+            {
+                true
+            }
+            "HWCAP2_WFXT" | "HWCAP2_EBF16" | "HWCAP2_SVE_EBF16" | "HWCAP2_CSSC"
+            | "HWCAP2_RPRFM" | "HWCAP2_SVE2P1" => {
+                musl || (gnu && versions.glibc.unwrap() < (2, 38))
+            }
+            "HWCAP2_MOPS" | "HWCAP2_HBC" | "HWCAP2_SVE_B16B16" | "HWCAP2_LRCPC3"
+            | "HWCAP2_LSE128" => musl || (gnu && versions.glibc.unwrap() < (2, 39)),
+            "HWCAP2_FPMR" | "HWCAP2_LUT" | "HWCAP2_FAMINMAX" | "HWCAP2_F8CVT" | "HWCAP2_F8FMA"
+            | "HWCAP2_F8DP4" | "HWCAP2_F8DP2" | "HWCAP2_F8E4M3" | "HWCAP2_F8E5M2"
+            | "HWCAP2_SME_LUTV2" | "HWCAP2_SME_F8F16" | "HWCAP2_SME_F8F32"
+            | "HWCAP2_SME_SF8FMA" | "HWCAP2_SME_SF8DP4" | "HWCAP2_SME_SF8DP2" | "HWCAP2_POE" => {
+                musl || (gnu && versions.glibc.unwrap() < (2, 41))
+            }
             "PTRACE_SET_SYSCALL_INFO" => kernel < (6, 16),
             "TLS_INFO_TX_MAX_PAYLOAD_LEN" | "TLS_INFO_MAX" => kernel < (6, 19),
 
             // Changed value recently
             "SW_MAX" | "SW_CNT" => kernel < (6, 16),
 
+            // x86 linear address masking and shadow stack `arch_prctl` codes
+            "ARCH_GET_UNTAG_MASK"
+            | "ARCH_ENABLE_TAGGED_ADDR"
+            | "ARCH_GET_MAX_TAG_BITS"
+            | "ARCH_FORCE_TAGGED_SVA" => kernel < (6, 4),
+            n if n.starts_with("ARCH_SHSTK_") => kernel < (6, 6),
+
+            // FUSE protocol additions
+            "FUSE_STATX" => kernel < (6, 6),
+            // Bumped with every FUSE protocol revision
+            "FUSE_KERNEL_MINOR_VERSION" => true,
+
             // Added in kernel versions 6.12..6.14 but we can't include `linux/fcntl.h`
             // (conflicts), so these need to wait on glibc's redefinition in 2.44-2.43.
             "AT_HANDLE_CONNECTABLE" | "AT_HANDLE_MNT_ID_UNIQUE" | "AT_EXECVE_CHECK" => {
@@ -4995,6 +5186,9 @@ fn test_linux(target: &str) {
                 }
             }
 
+            // Needs glibc 2.32 or later.
+            "PTHREAD_ATTR_NO_SIGMASK_NP" if gnu && versions.glibc.unwrap() < (2, 32) => true,
+
             // FIXME(musl): This value is not yet in musl.
             // eabihf targets are tested using an older version of glibc
             "AT_HANDLE_FID" if musl || eabihf => true,
@@ -5004,9 +5198,19 @@ fn test_linux(target: &str) {
     });
 
     let c_enums = [
+        "blk_zone_cond",
+        "blk_zone_report_flags",
+        "blk_zone_type",
         "can_state",
         "fsconfig_command",
+        "fuse_opcode",
+        "gpio_v2_line_attr_id",
+        "gpio_v2_line_changed_type",
+        "gpio_v2_line_event_id",
+        "gpio_v2_line_flag",
+        "kcmp_type",
         "membarrier_cmd",
+        "mtd_file_modes",
         "pid_type",
         "proc_cn_event",
         "proc_cn_mcast_op",
@@ -5062,6 +5266,17 @@ fn test_linux(target: &str) {
             // FIXME(linux): It now takes c_void instead of timezone since glibc 2.31.
             "gettimeofday" if gnu => true,
 
+            // Exported by glibc but not declared in any header.
+            "__libc_malloc" | "__libc_calloc" | "__libc_realloc" | "__libc_free"
+            | "__libc_memalign" | "__libc_valloc" | "__libc_pvalloc"
+                if gnu =>
+            {
+                true
+            }
+            // glibc exports the `arch_prctl` wrapper on x86_64, but no installed header declares
+            // it; programs are expected to provide their own prototype.
+            "arch_prctl" if gnu => true,
+
             // These are all implemented as static inline functions in uclibc, so
             // they cannot be linked against.
             // If implementations are required, they might need to be implemented
@@ -5101,7 +5316,6 @@ fn test_linux(target: &str) {
 
             // Needs glibc 2.33 or later.
             "mallinfo2" => true,
-
             // Not defined in uclibc as of 1.0.45
             "gettid" if uclibc => true,
             "getauxval" if uclibc => true,
@@ -5151,8 +5365,17 @@ fn test_linux(target: &str) {
             ("sockaddr_can", "can_addr") => true,
             // `anonymous_1` is an anonymous union
             ("ptp_perout_request", "anonymous_1") => true,
+            ("gpio_v2_line_attribute", "anonymous_1") => true,
             // `anonymous_2` is an anonymous union
             ("ptp_perout_request", "anonymous_2") => true,
+            // `ents` is an array of anonymous structs
+            ("ubi_rnvol_req", "ents") => true,
+            // `r` and `e` are anonymous unions
+            ("id_proc_event", "r" | "e") => true,
+            // `event_data` is an anonymous union
+            ("proc_event", "event_data") => true,
+            // Linux < 6.6 declares this as the nested `enum what`
+            ("proc_event", "what") => true,
             // FIXME(linux): `adjust_phase` requires >= 5.7 kernel headers
             // FIXME(linux): `max_phase_adj` requires >= 5.19 kernel headers
             // the rsv field shrunk when those fields got added, so is omitted too
@@ -5178,6 +5401,8 @@ fn test_linux(target: &str) {
             ("statvfs", "__f_unused") if musl => true,
             // _sigev_un is an anonymous union
             ("sigevent", "_sigev_un") => true,
+            // glibc wraps this in an anonymous union with a zero-length array
+            ("Dl_serinfo", "dls_serpath") => true,
             // signalfd had SIGSYS fields added in Linux 4.18, but no libc release
             // has them yet.
             (
@@ -5234,6 +5459,7 @@ fn test_linux(target: &str) {
             ("fanotify_event_info_fid", "handle") => true,
             // `anonymous_1` is an anonymous union
             ("ptp_perout_request", "anonymous_1") => true,
+            ("gpio_v2_line_attribute", "anonymous_1") => true,
             // `anonymous_2` is an anonymous union
             ("ptp_perout_request", "anonymous_2") => true,
             // FIXME(linux): `adjust_phase` requires >= 5.7 kernel headers
@@ -5244,6 +5470,8 @@ fn test_linux(target: &str) {
             {
                 true
             }
+            // Linux 6.9 (FUSE protocol 7.40)
+            ("fuse_init_out", "max_stack_depth") => kernel < (6, 9),
             // invalid application of 'sizeof' to incomplete type 'long unsigned int[]'
             ("mcontext_t", "__extcontext") if musl && loongarch64 => true,
             // FIXME(#4121): a new field was added from `f_spare`
@@ -5251,12 +5479,24 @@ fn test_linux(target: &str) {
             ("statvfs64", "__f_spare") => true,
             // the `xsk_tx_metadata_union` field is an anonymous union
             ("xsk_tx_metadata", "xsk_tx_metadata_union") => true,
+            // the `flags` field maps the 1-bit bitfields stored in an unsigned short
+            ("printf_info", "flags") => true,
+            // the `temp` field is an anonymous union
+            ("obstack", "temp") => true,
+            // the `flags` field maps the 1-bit bitfields stored in an unsigned int
+            ("obstack", "flags") => true,
             // After musl 1.2.0, the type becomes `int` instead of `long`.
             ("utmpx", "ut_session") if musl => true,
             // `frames` is a flexible array member
             ("bcm_msg_head", "frames") => true,
             // FAM
             ("af_alg_iv", "iv") => true,
+            ("cn_msg", "data") => true,
+            ("blk_zone_report", "zones") => true,
+            ("dm_target_deps", "dev") => true,
+            ("dm_name_list", "name") => true,
+            ("dm_target_versions", "name") => true,
+            ("dm_target_msg", "message") => true,
             ("file_handle", "f_handle") if musl || uclibc => true,
             // FIXME(ctest): ctest does not translate the rust code which computes the padding size
             ("pthread_cond_t", "__padding") if l4re => true,
@@ -5310,6 +5550,7 @@ fn test_linux(target: &str) {
         "fanotify_event_info_fid" => true,
         "cmsghdr" => true,
         "bcm_msg_head" => true,
+        "rand_pool_info" => true,
 
         // FIXME(linux): the call ABI of max_align_t is incorrect on these platforms:
         "max_align_t" if i686 || ppc64 => true,
@@ -0,0 +1,77 @@
+//! Check libc's reimplementations of the `<sys/time.h>` timeval and timespec macros.
+
+#![cfg(unix)]
+
+use std::mem;
+
+use libc::timeval;
+
+fn tv(sec: i64, usec: i64) -> timeval {
+    // SAFETY: all-zero is a valid `timeval`, including any padding fields
+    let mut t: timeval = unsafe { mem::zeroed() };
+    t.tv_sec = sec as _;
+    t.tv_usec = usec as _;
+    t
+}
+
+fn tv_parts(t: timeval) -> (i64, i64) {
+    (t.tv_sec as i64, t.tv_usec as i64)
+}
+
+fn timeradd(a: timeval, b: timeval) -> (i64, i64) {
+    let mut res = tv(-1, -1);
+    unsafe { libc::timeradd(&a, &b, &mut res) };
+    tv_parts(res)
+}
+
+fn timersub(a: timeval, b: timeval) -> (i64, i64) {
+    let mut res = tv(-1, -1);
+    unsafe { libc::timersub(&a, &b, &mut res) };
+    tv_parts(res)
+}
+
+#[test]
+fn test_timerisset_timerclear() {
+    unsafe {
+        assert!(!libc::timerisset(&tv(0, 0)));
+        assert!(libc::timerisset(&tv(0, 1)));
+        assert!(libc::timerisset(&tv(1, 0)));
+
+        let mut t = tv(5, 6);
+        libc::timerclear(&mut t);
+        assert_eq!(tv_parts(t), (0, 0));
+    }
+}
+
+#[test]
+fn test_timeradd_timersub() {
+    assert_eq!(timeradd(tv(1, 600_000), tv(2, 500_000)), (4, 100_000));
+    assert_eq!(timeradd(tv(1, 1), tv(0, 2)), (1, 3));
+    assert_eq!(timersub(tv(4, 100_000), tv(2, 500_000)), (1, 600_000));
+    assert_eq!(timersub(tv(0, 0), tv(0, 1)), (-1, 999_999));
+}
+
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "dragonfly",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+#[test]
+fn test_timespecadd_timespecsub() {
+    fn ts(sec: i64, nsec: i64) -> libc::timespec {
+        // SAFETY: all-zero is a valid `timespec`, including any padding fields
+        let mut t: libc::timespec = unsafe { mem::zeroed() };
+        t.tv_sec = sec as _;
+        t.tv_nsec = nsec as _;
+        t
+    }
+
+    unsafe {
+        let mut res = ts(0, 0);
+        libc::timespecadd(&ts(1, 600_000_000), &ts(0, 500_000_000), &mut res);
+        assert_eq!((res.tv_sec as i64, res.tv_nsec as i64), (2, 100_000_000));
+        libc::timespecsub(&ts(2, 100_000_000), &ts(0, 500_000_000), &mut res);
+        assert_eq!((res.tv_sec as i64, res.tv_nsec as i64), (1, 600_000_000));
+    }
+}
@@ -3253,7 +3253,10 @@ extern "C" {
     pub fn abort() -> !;
     pub fn exit(status: c_int) -> !;
     pub fn _exit(status: c_int) -> !;
+    pub fn _Exit(status: c_int) -> !;
     pub fn atexit(cb: extern "C" fn()) -> c_int;
+    pub fn quick_exit(status: c_int) -> !;
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
     pub fn system(s: *const c_char) -> c_int;
     pub fn getenv(s: *const c_char) -> *mut c_char;
 
@@ -3598,6 +3601,9 @@ extern "C" {
     pub fn getprotobyname(name: *const c_char) -> *mut protoent;
     pub fn getprotobynumber(proto: c_int) -> *mut protoent;
     pub fn usleep(secs: c_uint) -> c_int;
+    pub fn nice(incr: c_int) -> c_int;
+    pub fn getpriority(which: c_int, who: crate::id_t) -> c_int;
+    pub fn setpriority(which: c_int, who: crate::id_t, prio: c_int) -> c_int;
     pub fn send(socket: c_int, buf: *const c_void, len: size_t, flags: c_int) -> ssize_t;
     pub fn recv(socket: c_int, buf: *mut c_void, len: size_t, flags: c_int) -> ssize_t;
     pub fn putenv(string: *mut c_char) -> c_int;
@@ -3695,6 +3701,14 @@ extern "C" {
     pub fn statfs(path: *const c_char, buf: *mut statfs) -> c_int;
     pub fn fstatfs(fd: c_int, buf: *mut statfs) -> c_int;
     pub fn memrchr(cx: *const c_void, c: c_int, n: size_t) -> *mut c_void;
+    pub fn memmem(
+        haystack: *const c_void,
+        haystacklen: size_t,
+        needle: *const c_void,
+        needlelen: size_t,
+    ) -> *mut c_void;
+    pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
+    pub fn strsep(string: *mut *mut c_char, delim: *const c_char) -> *mut c_char;
 
     pub fn posix_fadvise(fd: c_int, offset: off_t, len: off_t, advise: c_int) -> c_int;
     pub fn futimens(fd: c_int, times: *const crate::timespec) -> c_int;
@@ -58,12 +58,16 @@ pub const PTHREAD_ONCE_INIT: crate::pthread_once_t = crate::pthread_once_t {
 };
 
 pub use crate::new::common::posix::pthread::{
+    pthread_attr_getguardsize,
     pthread_attr_getinheritsched,
     pthread_attr_getschedparam,
     pthread_attr_getschedpolicy,
+    pthread_attr_getstack,
+    pthread_attr_setguardsize,
     pthread_attr_setinheritsched,
     pthread_attr_setschedparam,
     pthread_attr_setschedpolicy,
+    pthread_attr_setstack,
     pthread_condattr_getpshared,
     pthread_condattr_setpshared,
     pthread_getschedparam,
@@ -2,6 +2,8 @@
 //!
 //! <https://github.com/apple-oss-distributions/libpthread/blob/main/include/sys/qos.h>
 
+use crate::prelude::*;
+
 c_enum! {
     #[repr(u32)]
     pub enum qos_class_t {
@@ -13,3 +15,10 @@ c_enum! {
         pub QOS_CLASS_UNSPECIFIED = 0x00,
     }
 }
+
+pub const QOS_MIN_RELATIVE_PRIORITY: c_int = -15;
+
+extern "C" {
+    pub fn qos_class_self() -> qos_class_t;
+    pub fn qos_class_main() -> qos_class_t;
+}
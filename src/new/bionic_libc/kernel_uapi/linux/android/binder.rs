@@ -0,0 +1,287 @@
+//! Header: `bionic/libc/kernel/uapi/linux/android/binder.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+const fn b_pack_chars(c1: u8, c2: u8, c3: u8, c4: u8) -> crate::__u32 {
+    ((c1 as crate::__u32) << 24)
+        | ((c2 as crate::__u32) << 16)
+        | ((c3 as crate::__u32) << 8)
+        | (c4 as crate::__u32)
+}
+
+pub const B_TYPE_LARGE: u8 = 0x85;
+
+pub const BINDER_TYPE_BINDER: crate::__u32 = b_pack_chars(b's', b'b', b'*', B_TYPE_LARGE);
+pub const BINDER_TYPE_WEAK_BINDER: crate::__u32 = b_pack_chars(b'w', b'b', b'*', B_TYPE_LARGE);
+pub const BINDER_TYPE_HANDLE: crate::__u32 = b_pack_chars(b's', b'h', b'*', B_TYPE_LARGE);
+pub const BINDER_TYPE_WEAK_HANDLE: crate::__u32 = b_pack_chars(b'w', b'h', b'*', B_TYPE_LARGE);
+pub const BINDER_TYPE_FD: crate::__u32 = b_pack_chars(b'f', b'd', b'*', B_TYPE_LARGE);
+pub const BINDER_TYPE_FDA: crate::__u32 = b_pack_chars(b'f', b'd', b'a', B_TYPE_LARGE);
+pub const BINDER_TYPE_PTR: crate::__u32 = b_pack_chars(b'p', b't', b'*', B_TYPE_LARGE);
+
+pub const FLAT_BINDER_FLAG_PRIORITY_MASK: crate::__u32 = 0xff;
+pub const FLAT_BINDER_FLAG_ACCEPTS_FDS: crate::__u32 = 0x100;
+pub const FLAT_BINDER_FLAG_TXN_SECURITY_CTX: crate::__u32 = 0x1000;
+
+pub type binder_size_t = crate::__u64;
+pub type binder_uintptr_t = crate::__u64;
+
+s! {
+    pub struct binder_object_header {
+        pub type_: crate::__u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_flat_binder_object_1 {
+        pub binder: binder_uintptr_t,
+        pub handle: crate::__u32,
+    }
+
+    pub struct flat_binder_object {
+        pub hdr: binder_object_header,
+        pub flags: crate::__u32,
+        pub anonymous_1: __c_anonymous_flat_binder_object_1,
+        pub cookie: binder_uintptr_t,
+    }
+
+    pub union __c_anonymous_binder_fd_object_1 {
+        pub pad_binder: binder_uintptr_t,
+        pub fd: crate::__u32,
+    }
+
+    pub struct binder_fd_object {
+        pub hdr: binder_object_header,
+        pub pad_flags: crate::__u32,
+        pub anonymous_1: __c_anonymous_binder_fd_object_1,
+        pub cookie: binder_uintptr_t,
+    }
+}
+
+s! {
+    pub struct binder_buffer_object {
+        pub hdr: binder_object_header,
+        pub flags: crate::__u32,
+        pub buffer: binder_uintptr_t,
+        pub length: binder_size_t,
+        pub parent: binder_size_t,
+        pub parent_offset: binder_size_t,
+    }
+}
+
+pub const BINDER_BUFFER_FLAG_HAS_PARENT: crate::__u32 = 0x01;
+
+s! {
+    pub struct binder_fd_array_object {
+        pub hdr: binder_object_header,
+        pad: Padding<crate::__u32>,
+        pub num_fds: binder_size_t,
+        pub parent: binder_size_t,
+        pub parent_offset: binder_size_t,
+    }
+
+    pub struct binder_write_read {
+        pub write_size: binder_size_t,
+        pub write_consumed: binder_size_t,
+        pub write_buffer: binder_uintptr_t,
+        pub read_size: binder_size_t,
+        pub read_consumed: binder_size_t,
+        pub read_buffer: binder_uintptr_t,
+    }
+
+    pub struct binder_version {
+        pub protocol_version: crate::__s32,
+    }
+}
+
+pub const BINDER_CURRENT_PROTOCOL_VERSION: crate::__s32 = 8;
+
+s! {
+    pub struct binder_node_debug_info {
+        pub ptr: binder_uintptr_t,
+        pub cookie: binder_uintptr_t,
+        pub has_strong_ref: crate::__u32,
+        pub has_weak_ref: crate::__u32,
+    }
+
+    pub struct binder_node_info_for_ref {
+        pub handle: crate::__u32,
+        pub strong_count: crate::__u32,
+        pub weak_count: crate::__u32,
+        reserved1: Padding<crate::__u32>,
+        reserved2: Padding<crate::__u32>,
+        reserved3: Padding<crate::__u32>,
+    }
+
+    pub struct binder_freeze_info {
+        pub pid: crate::__u32,
+        pub enable: crate::__u32,
+        pub timeout_ms: crate::__u32,
+    }
+
+    pub struct binder_frozen_status_info {
+        pub pid: crate::__u32,
+        pub sync_recv: crate::__u32,
+        pub async_recv: crate::__u32,
+    }
+
+    pub struct binder_extended_error {
+        pub id: crate::__u32,
+        pub command: crate::__u32,
+        pub param: crate::__s32,
+    }
+}
+
+const BINDER_IOC_MAGIC: u32 = b'b' as u32;
+
+pub const BINDER_WRITE_READ: Ioctl = _IOWR::<binder_write_read>(BINDER_IOC_MAGIC, 1);
+pub const BINDER_SET_IDLE_TIMEOUT: Ioctl = _IOW::<crate::__s64>(BINDER_IOC_MAGIC, 3);
+pub const BINDER_SET_MAX_THREADS: Ioctl = _IOW::<crate::__u32>(BINDER_IOC_MAGIC, 5);
+pub const BINDER_SET_IDLE_PRIORITY: Ioctl = _IOW::<crate::__s32>(BINDER_IOC_MAGIC, 6);
+pub const BINDER_SET_CONTEXT_MGR: Ioctl = _IOW::<crate::__s32>(BINDER_IOC_MAGIC, 7);
+pub const BINDER_THREAD_EXIT: Ioctl = _IOW::<crate::__s32>(BINDER_IOC_MAGIC, 8);
+pub const BINDER_VERSION: Ioctl = _IOWR::<binder_version>(BINDER_IOC_MAGIC, 9);
+pub const BINDER_GET_NODE_DEBUG_INFO: Ioctl = _IOWR::<binder_node_debug_info>(BINDER_IOC_MAGIC, 11);
+pub const BINDER_GET_NODE_INFO_FOR_REF: Ioctl =
+    _IOWR::<binder_node_info_for_ref>(BINDER_IOC_MAGIC, 12);
+pub const BINDER_SET_CONTEXT_MGR_EXT: Ioctl = _IOW::<flat_binder_object>(BINDER_IOC_MAGIC, 13);
+pub const BINDER_FREEZE: Ioctl = _IOW::<binder_freeze_info>(BINDER_IOC_MAGIC, 14);
+pub const BINDER_GET_FROZEN_INFO: Ioctl = _IOWR::<binder_frozen_status_info>(BINDER_IOC_MAGIC, 15);
+pub const BINDER_ENABLE_ONEWAY_SPAM_DETECTION: Ioctl = _IOW::<crate::__u32>(BINDER_IOC_MAGIC, 16);
+pub const BINDER_GET_EXTENDED_ERROR: Ioctl = _IOWR::<binder_extended_error>(BINDER_IOC_MAGIC, 17);
+
+// enum transaction_flags
+pub const TF_ONE_WAY: crate::__u32 = 0x01;
+pub const TF_ROOT_OBJECT: crate::__u32 = 0x04;
+pub const TF_STATUS_CODE: crate::__u32 = 0x08;
+pub const TF_ACCEPT_FDS: crate::__u32 = 0x10;
+pub const TF_CLEAR_BUF: crate::__u32 = 0x20;
+pub const TF_UPDATE_TXN: crate::__u32 = 0x40;
+
+s_no_extra_traits! {
+    pub union __c_anonymous_binder_transaction_data_target {
+        pub handle: crate::__u32,
+        pub ptr: binder_uintptr_t,
+    }
+
+    pub union __c_anonymous_binder_transaction_data_data {
+        pub ptr: __c_anonymous_binder_transaction_data_data_ptr,
+        pub buf: [crate::__u8; 8],
+    }
+
+    pub struct binder_transaction_data {
+        pub target: __c_anonymous_binder_transaction_data_target,
+        pub cookie: binder_uintptr_t,
+        pub code: crate::__u32,
+        pub flags: crate::__u32,
+        pub sender_pid: crate::pid_t,
+        pub sender_euid: crate::uid_t,
+        pub data_size: binder_size_t,
+        pub offsets_size: binder_size_t,
+        pub data: __c_anonymous_binder_transaction_data_data,
+    }
+
+    pub struct binder_transaction_data_secctx {
+        pub transaction_data: binder_transaction_data,
+        pub secctx: binder_uintptr_t,
+    }
+
+    pub struct binder_transaction_data_sg {
+        pub transaction_data: binder_transaction_data,
+        pub buffers_size: binder_size_t,
+    }
+}
+
+s! {
+    pub struct __c_anonymous_binder_transaction_data_data_ptr {
+        pub buffer: binder_uintptr_t,
+        pub offsets: binder_uintptr_t,
+    }
+
+    pub struct binder_ptr_cookie {
+        pub ptr: binder_uintptr_t,
+        pub cookie: binder_uintptr_t,
+    }
+
+    #[repr(packed)]
+    pub struct binder_handle_cookie {
+        pub handle: crate::__u32,
+        pub cookie: binder_uintptr_t,
+    }
+
+    pub struct binder_pri_desc {
+        pub priority: crate::__s32,
+        pub desc: crate::__u32,
+    }
+
+    pub struct binder_pri_ptr_cookie {
+        pub priority: crate::__s32,
+        pub ptr: binder_uintptr_t,
+        pub cookie: binder_uintptr_t,
+    }
+}
+
+const BR_MAGIC: u32 = b'r' as u32;
+
+// enum binder_driver_return_protocol
+pub const BR_ERROR: crate::__u32 = _IOR::<crate::__s32>(BR_MAGIC, 0) as crate::__u32;
+pub const BR_OK: crate::__u32 = _IO(BR_MAGIC, 1) as crate::__u32;
+pub const BR_TRANSACTION_SEC_CTX: crate::__u32 =
+    _IOR::<binder_transaction_data_secctx>(BR_MAGIC, 2) as crate::__u32;
+pub const BR_TRANSACTION: crate::__u32 =
+    _IOR::<binder_transaction_data>(BR_MAGIC, 2) as crate::__u32;
+pub const BR_REPLY: crate::__u32 = _IOR::<binder_transaction_data>(BR_MAGIC, 3) as crate::__u32;
+pub const BR_ACQUIRE_RESULT: crate::__u32 = _IOR::<crate::__s32>(BR_MAGIC, 4) as crate::__u32;
+pub const BR_DEAD_REPLY: crate::__u32 = _IO(BR_MAGIC, 5) as crate::__u32;
+pub const BR_TRANSACTION_COMPLETE: crate::__u32 = _IO(BR_MAGIC, 6) as crate::__u32;
+pub const BR_INCREFS: crate::__u32 = _IOR::<binder_ptr_cookie>(BR_MAGIC, 7) as crate::__u32;
+pub const BR_ACQUIRE: crate::__u32 = _IOR::<binder_ptr_cookie>(BR_MAGIC, 8) as crate::__u32;
+pub const BR_RELEASE: crate::__u32 = _IOR::<binder_ptr_cookie>(BR_MAGIC, 9) as crate::__u32;
+pub const BR_DECREFS: crate::__u32 = _IOR::<binder_ptr_cookie>(BR_MAGIC, 10) as crate::__u32;
+pub const BR_ATTEMPT_ACQUIRE: crate::__u32 =
+    _IOR::<binder_pri_ptr_cookie>(BR_MAGIC, 11) as crate::__u32;
+pub const BR_NOOP: crate::__u32 = _IO(BR_MAGIC, 12) as crate::__u32;
+pub const BR_SPAWN_LOOPER: crate::__u32 = _IO(BR_MAGIC, 13) as crate::__u32;
+pub const BR_FINISHED: crate::__u32 = _IO(BR_MAGIC, 14) as crate::__u32;
+pub const BR_DEAD_BINDER: crate::__u32 = _IOR::<binder_uintptr_t>(BR_MAGIC, 15) as crate::__u32;
+pub const BR_CLEAR_DEATH_NOTIFICATION_DONE: crate::__u32 =
+    _IOR::<binder_uintptr_t>(BR_MAGIC, 16) as crate::__u32;
+pub const BR_FAILED_REPLY: crate::__u32 = _IO(BR_MAGIC, 17) as crate::__u32;
+pub const BR_FROZEN_REPLY: crate::__u32 = _IO(BR_MAGIC, 18) as crate::__u32;
+pub const BR_ONEWAY_SPAM_SUSPECT: crate::__u32 = _IO(BR_MAGIC, 19) as crate::__u32;
+
+const BC_MAGIC: u32 = b'c' as u32;
+
+// enum binder_driver_command_protocol
+pub const BC_TRANSACTION: crate::__u32 =
+    _IOW::<binder_transaction_data>(BC_MAGIC, 0) as crate::__u32;
+pub const BC_REPLY: crate::__u32 = _IOW::<binder_transaction_data>(BC_MAGIC, 1) as crate::__u32;
+pub const BC_ACQUIRE_RESULT: crate::__u32 = _IOW::<crate::__s32>(BC_MAGIC, 2) as crate::__u32;
+pub const BC_FREE_BUFFER: crate::__u32 = _IOW::<binder_uintptr_t>(BC_MAGIC, 3) as crate::__u32;
+pub const BC_INCREFS: crate::__u32 = _IOW::<crate::__u32>(BC_MAGIC, 4) as crate::__u32;
+pub const BC_ACQUIRE: crate::__u32 = _IOW::<crate::__u32>(BC_MAGIC, 5) as crate::__u32;
+pub const BC_RELEASE: crate::__u32 = _IOW::<crate::__u32>(BC_MAGIC, 6) as crate::__u32;
+pub const BC_DECREFS: crate::__u32 = _IOW::<crate::__u32>(BC_MAGIC, 7) as crate::__u32;
+pub const BC_INCREFS_DONE: crate::__u32 = _IOW::<binder_ptr_cookie>(BC_MAGIC, 8) as crate::__u32;
+pub const BC_ACQUIRE_DONE: crate::__u32 = _IOW::<binder_ptr_cookie>(BC_MAGIC, 9) as crate::__u32;
+pub const BC_ATTEMPT_ACQUIRE: crate::__u32 = _IOW::<binder_pri_desc>(BC_MAGIC, 10) as crate::__u32;
+pub const BC_REGISTER_LOOPER: crate::__u32 = _IO(BC_MAGIC, 11) as crate::__u32;
+pub const BC_ENTER_LOOPER: crate::__u32 = _IO(BC_MAGIC, 12) as crate::__u32;
+pub const BC_EXIT_LOOPER: crate::__u32 = _IO(BC_MAGIC, 13) as crate::__u32;
+pub const BC_REQUEST_DEATH_NOTIFICATION: crate::__u32 =
+    _IOW::<binder_handle_cookie>(BC_MAGIC, 14) as crate::__u32;
+pub const BC_CLEAR_DEATH_NOTIFICATION: crate::__u32 =
+    _IOW::<binder_handle_cookie>(BC_MAGIC, 15) as crate::__u32;
+pub const BC_DEAD_BINDER_DONE: crate::__u32 =
+    _IOW::<binder_uintptr_t>(BC_MAGIC, 16) as crate::__u32;
+pub const BC_TRANSACTION_SG: crate::__u32 =
+    _IOW::<binder_transaction_data_sg>(BC_MAGIC, 17) as crate::__u32;
+pub const BC_REPLY_SG: crate::__u32 =
+    _IOW::<binder_transaction_data_sg>(BC_MAGIC, 18) as crate::__u32;
@@ -0,0 +1,5 @@
+//! Directory: `bionic/libc/kernel/uapi/linux/android/`
+//!
+//! <https://cs.android.com/android/platform/superproject/main/+/main:bionic/libc/kernel/uapi/linux/android/>
+
+pub(crate) mod binder;
@@ -0,0 +1,37 @@
+//! Header: `bionic/libc/kernel/uapi/linux/ashmem.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+};
+
+pub const ASHMEM_NAME_LEN: usize = 256;
+
+pub const ASHMEM_NOT_PURGED: c_int = 0;
+pub const ASHMEM_WAS_PURGED: c_int = 1;
+
+pub const ASHMEM_IS_UNPINNED: c_int = 0;
+pub const ASHMEM_IS_PINNED: c_int = 1;
+
+s! {
+    pub struct ashmem_pin {
+        pub offset: crate::__u32,
+        pub len: crate::__u32,
+    }
+}
+
+const __ASHMEMIOC: c_uint = 0x77;
+
+pub const ASHMEM_SET_NAME: Ioctl = _IOW::<[c_char; ASHMEM_NAME_LEN]>(__ASHMEMIOC, 1);
+pub const ASHMEM_GET_NAME: Ioctl = _IOR::<[c_char; ASHMEM_NAME_LEN]>(__ASHMEMIOC, 2);
+pub const ASHMEM_SET_SIZE: Ioctl = _IOW::<size_t>(__ASHMEMIOC, 3);
+pub const ASHMEM_GET_SIZE: Ioctl = _IO(__ASHMEMIOC, 4);
+pub const ASHMEM_SET_PROT_MASK: Ioctl = _IOW::<c_ulong>(__ASHMEMIOC, 5);
+pub const ASHMEM_GET_PROT_MASK: Ioctl = _IO(__ASHMEMIOC, 6);
+pub const ASHMEM_PIN: Ioctl = _IOW::<ashmem_pin>(__ASHMEMIOC, 7);
+pub const ASHMEM_UNPIN: Ioctl = _IOW::<ashmem_pin>(__ASHMEMIOC, 8);
+pub const ASHMEM_GET_PIN_STATUS: Ioctl = _IO(__ASHMEMIOC, 9);
+pub const ASHMEM_PURGE_ALL_CACHES: Ioctl = _IO(__ASHMEMIOC, 10);
@@ -2,4 +2,8 @@
 //!
 //! <https://cs.android.com/android/platform/superproject/main/+/main:bionic/libc/kernel/uapi/linux/>
 
+pub(crate) mod android;
+pub(crate) mod ashmem;
+pub(crate) mod mqueue;
+pub(crate) mod sync_file;
 pub(crate) mod types;
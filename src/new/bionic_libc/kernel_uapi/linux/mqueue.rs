@@ -0,0 +1,24 @@
+//! Header: `bionic/libc/kernel/uapi/linux/mqueue.h`
+//!
+//! Bionic does not provide the `mq_*` wrappers, so message queues have to be used through
+//! `syscall` with the `SYS_mq_*` numbers.
+
+use crate::prelude::*;
+
+pub const MQ_PRIO_MAX: c_int = 32768;
+pub const MQ_BYTES_MAX: c_int = 819200;
+
+s! {
+    pub struct mq_attr {
+        pub mq_flags: c_long,
+        pub mq_maxmsg: c_long,
+        pub mq_msgsize: c_long,
+        pub mq_curmsgs: c_long,
+        __reserved: Padding<[c_long; 4]>,
+    }
+}
+
+pub const NOTIFY_NONE: c_int = 0;
+pub const NOTIFY_WOKENUP: c_int = 1;
+pub const NOTIFY_REMOVED: c_int = 2;
+pub const NOTIFY_COOKIE_LEN: c_int = 32;
@@ -0,0 +1,39 @@
+//! Header: `bionic/libc/kernel/uapi/linux/sync_file.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOWR,
+};
+
+s! {
+    pub struct sync_merge_data {
+        pub name: [c_char; 32],
+        pub fd2: crate::__s32,
+        pub fence: crate::__s32,
+        pub flags: crate::__u32,
+        pad: Padding<crate::__u32>,
+    }
+
+    pub struct sync_fence_info {
+        pub obj_name: [c_char; 32],
+        pub driver_name: [c_char; 32],
+        pub status: crate::__s32,
+        pub flags: crate::__u32,
+        pub timestamp_ns: crate::__u64,
+    }
+
+    pub struct sync_file_info {
+        pub name: [c_char; 32],
+        pub status: crate::__s32,
+        pub flags: crate::__u32,
+        pub num_fences: crate::__u32,
+        pad: Padding<crate::__u32>,
+        pub sync_fence_info: crate::__u64,
+    }
+}
+
+pub const SYNC_IOC_MAGIC: c_uint = '>' as c_uint;
+
+pub const SYNC_IOC_MERGE: Ioctl = _IOWR::<sync_merge_data>(SYNC_IOC_MAGIC, 3);
+pub const SYNC_IOC_FILE_INFO: Ioctl = _IOWR::<sync_file_info>(SYNC_IOC_MAGIC, 4);
@@ -12,7 +12,12 @@ extern "C" {
         child: Option<unsafe extern "C" fn()>,
     ) -> c_int;
 
-    #[cfg(any(target_os = "android", target_os = "l4re", target_os = "linux"))]
+    #[cfg(any(
+        target_os = "android",
+        target_os = "l4re",
+        target_os = "linux",
+        target_vendor = "apple"
+    ))]
     pub fn pthread_attr_getguardsize(
         attr: *const crate::pthread_attr_t,
         guardsize: *mut size_t,
@@ -45,7 +50,8 @@ extern "C" {
         target_os = "android",
         target_os = "emscripten",
         target_os = "linux",
-        target_os = "l4re"
+        target_os = "l4re",
+        target_vendor = "apple"
     ))]
     pub fn pthread_attr_getstack(
         attr: *const crate::pthread_attr_t,
@@ -53,7 +59,12 @@ extern "C" {
         stacksize: *mut size_t,
     ) -> c_int;
 
-    #[cfg(any(target_os = "android", target_os = "l4re", target_os = "linux"))]
+    #[cfg(any(
+        target_os = "android",
+        target_os = "l4re",
+        target_os = "linux",
+        target_vendor = "apple"
+    ))]
     pub fn pthread_attr_setguardsize(attr: *mut crate::pthread_attr_t, guardsize: size_t) -> c_int;
 
     #[cfg(any(
@@ -80,7 +91,8 @@ extern "C" {
         target_os = "android",
         target_os = "emscripten",
         target_os = "linux",
-        target_os = "l4re"
+        target_os = "l4re",
+        target_vendor = "apple"
     ))]
     pub fn pthread_attr_setstack(
         attr: *mut crate::pthread_attr_t,
@@ -0,0 +1,141 @@
+//! Header: `argp.h`
+//!
+//! <https://github.com/bminor/glibc/blob/master/argp/argp.h>
+
+use crate::prelude::*;
+use crate::FILE;
+
+pub type error_t = c_int;
+
+pub type argp_parser_t =
+    Option<unsafe extern "C" fn(key: c_int, arg: *mut c_char, state: *mut argp_state) -> error_t>;
+
+s! {
+    pub struct argp_option {
+        pub name: *const c_char,
+        pub key: c_int,
+        pub arg: *const c_char,
+        pub flags: c_int,
+        pub doc: *const c_char,
+        pub group: c_int,
+    }
+
+    #[allow(unpredictable_function_pointer_comparisons)]
+    pub struct argp {
+        pub options: *const argp_option,
+        pub parser: argp_parser_t,
+        pub args_doc: *const c_char,
+        pub doc: *const c_char,
+        pub children: *const argp_child,
+        pub help_filter: Option<
+            unsafe extern "C" fn(key: c_int, text: *const c_char, input: *mut c_void) -> *mut c_char,
+        >,
+        pub argp_domain: *const c_char,
+    }
+
+    pub struct argp_child {
+        pub argp: *const argp,
+        pub flags: c_int,
+        pub header: *const c_char,
+        pub group: c_int,
+    }
+
+    pub struct argp_state {
+        pub root_argp: *const argp,
+        pub argc: c_int,
+        pub argv: *mut *mut c_char,
+        pub next: c_int,
+        pub flags: c_uint,
+        pub arg_num: c_uint,
+        pub quoted: c_int,
+        pub input: *mut c_void,
+        pub child_inputs: *mut *mut c_void,
+        pub hook: *mut c_void,
+        pub name: *mut c_char,
+        pub err_stream: *mut FILE,
+        pub out_stream: *mut FILE,
+        pub pstate: *mut c_void,
+    }
+}
+
+// `argp_option` flags
+pub const OPTION_ARG_OPTIONAL: c_int = 0x1;
+pub const OPTION_HIDDEN: c_int = 0x2;
+pub const OPTION_ALIAS: c_int = 0x4;
+pub const OPTION_DOC: c_int = 0x8;
+pub const OPTION_NO_USAGE: c_int = 0x10;
+
+// Special keys passed to the parser function
+pub const ARGP_KEY_ARG: c_int = 0;
+pub const ARGP_KEY_ARGS: c_int = 0x1000006;
+pub const ARGP_KEY_END: c_int = 0x1000001;
+pub const ARGP_KEY_NO_ARGS: c_int = 0x1000002;
+pub const ARGP_KEY_INIT: c_int = 0x1000003;
+pub const ARGP_KEY_FINI: c_int = 0x1000007;
+pub const ARGP_KEY_SUCCESS: c_int = 0x1000004;
+pub const ARGP_KEY_ERROR: c_int = 0x1000005;
+
+// Special keys passed to the `help_filter` function
+pub const ARGP_KEY_HELP_PRE_DOC: c_int = 0x2000001;
+pub const ARGP_KEY_HELP_POST_DOC: c_int = 0x2000002;
+pub const ARGP_KEY_HELP_HEADER: c_int = 0x2000003;
+pub const ARGP_KEY_HELP_EXTRA: c_int = 0x2000004;
+pub const ARGP_KEY_HELP_DUP_ARGS_NOTE: c_int = 0x2000005;
+pub const ARGP_KEY_HELP_ARGS_DOC: c_int = 0x2000006;
+
+pub const ARGP_ERR_UNKNOWN: error_t = crate::E2BIG;
+
+// `argp_parse` flags
+pub const ARGP_PARSE_ARGV0: c_uint = 0x01;
+pub const ARGP_NO_ERRS: c_uint = 0x02;
+pub const ARGP_NO_ARGS: c_uint = 0x04;
+pub const ARGP_IN_ORDER: c_uint = 0x08;
+pub const ARGP_NO_HELP: c_uint = 0x10;
+pub const ARGP_NO_EXIT: c_uint = 0x20;
+pub const ARGP_LONG_ONLY: c_uint = 0x40;
+pub const ARGP_SILENT: c_uint = ARGP_NO_EXIT | ARGP_NO_ERRS | ARGP_NO_HELP;
+
+// `argp_help` flags
+pub const ARGP_HELP_USAGE: c_uint = 0x01;
+pub const ARGP_HELP_SHORT_USAGE: c_uint = 0x02;
+pub const ARGP_HELP_SEE: c_uint = 0x04;
+pub const ARGP_HELP_LONG: c_uint = 0x08;
+pub const ARGP_HELP_PRE_DOC: c_uint = 0x10;
+pub const ARGP_HELP_POST_DOC: c_uint = 0x20;
+pub const ARGP_HELP_DOC: c_uint = ARGP_HELP_PRE_DOC | ARGP_HELP_POST_DOC;
+pub const ARGP_HELP_BUG_ADDR: c_uint = 0x40;
+pub const ARGP_HELP_LONG_ONLY: c_uint = 0x80;
+pub const ARGP_HELP_EXIT_ERR: c_uint = 0x100;
+pub const ARGP_HELP_EXIT_OK: c_uint = 0x200;
+pub const ARGP_HELP_STD_ERR: c_uint = ARGP_HELP_SEE | ARGP_HELP_EXIT_ERR;
+pub const ARGP_HELP_STD_USAGE: c_uint = ARGP_HELP_SHORT_USAGE | ARGP_HELP_SEE | ARGP_HELP_EXIT_ERR;
+pub const ARGP_HELP_STD_HELP: c_uint =
+    ARGP_HELP_SHORT_USAGE | ARGP_HELP_LONG | ARGP_HELP_EXIT_OK | ARGP_HELP_DOC | ARGP_HELP_BUG_ADDR;
+
+extern "C" {
+    pub static mut argp_program_version: *const c_char;
+    pub static mut argp_program_version_hook:
+        Option<unsafe extern "C" fn(stream: *mut FILE, state: *mut argp_state)>;
+    pub static mut argp_program_bug_address: *const c_char;
+    pub static mut argp_err_exit_status: error_t;
+
+    pub fn argp_parse(
+        argp: *const argp,
+        argc: c_int,
+        argv: *mut *mut c_char,
+        flags: c_uint,
+        arg_index: *mut c_int,
+        input: *mut c_void,
+    ) -> error_t;
+    pub fn argp_help(argp: *const argp, stream: *mut FILE, flags: c_uint, name: *mut c_char);
+    pub fn argp_state_help(state: *const argp_state, stream: *mut FILE, flags: c_uint);
+    pub fn argp_usage(state: *const argp_state);
+    pub fn argp_error(state: *const argp_state, fmt: *const c_char, ...);
+    pub fn argp_failure(
+        state: *const argp_state,
+        status: c_int,
+        errnum: c_int,
+        fmt: *const c_char,
+        ...
+    );
+}
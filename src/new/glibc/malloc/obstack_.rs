@@ -0,0 +1,60 @@
+//! Header: `obstack.h`
+//!
+//! <https://github.com/bminor/glibc/blob/master/malloc/obstack.h>
+
+use crate::prelude::*;
+
+s! {
+    pub struct _obstack_chunk {
+        pub limit: *mut c_char,
+        pub prev: *mut _obstack_chunk,
+        pub contents: [c_char; 4],
+    }
+}
+
+s_no_extra_traits! {
+    pub struct obstack {
+        pub chunk_size: c_long,
+        pub chunk: *mut _obstack_chunk,
+        pub object_base: *mut c_char,
+        pub next_free: *mut c_char,
+        pub chunk_limit: *mut c_char,
+        pub temp: __c_anonymous_obstack_temp,
+        pub alignment_mask: c_int,
+        pub chunkfun: Option<unsafe extern "C" fn(*mut c_void, c_long) -> *mut _obstack_chunk>,
+        pub freefun: Option<unsafe extern "C" fn(*mut c_void, *mut _obstack_chunk)>,
+        pub extra_arg: *mut c_void,
+        /// This contains the 1-bit flags `use_extra_arg`, `maybe_empty_object` and
+        /// `alloc_failed`, in that order starting from the least significant bit.
+        pub flags: c_uint,
+    }
+
+    pub union __c_anonymous_obstack_temp {
+        pub tempint: crate::ptrdiff_t,
+        pub tempptr: *mut c_void,
+    }
+}
+
+extern "C" {
+    pub static mut obstack_alloc_failed_handler: Option<unsafe extern "C" fn()>;
+    pub static mut obstack_exit_failure: c_int;
+
+    pub fn _obstack_newchunk(h: *mut obstack, length: c_int);
+    pub fn _obstack_begin(
+        h: *mut obstack,
+        size: c_int,
+        alignment: c_int,
+        chunkfun: Option<unsafe extern "C" fn(size: c_long) -> *mut c_void>,
+        freefun: Option<unsafe extern "C" fn(ptr: *mut c_void)>,
+    ) -> c_int;
+    pub fn _obstack_begin_1(
+        h: *mut obstack,
+        size: c_int,
+        alignment: c_int,
+        chunkfun: Option<unsafe extern "C" fn(arg: *mut c_void, size: c_long) -> *mut c_void>,
+        freefun: Option<unsafe extern "C" fn(arg: *mut c_void, ptr: *mut c_void)>,
+        arg: *mut c_void,
+    ) -> c_int;
+    pub fn _obstack_memory_used(h: *mut obstack) -> c_int;
+    pub fn obstack_free(h: *mut obstack, obj: *mut c_void);
+}
@@ -0,0 +1,21 @@
+//! Header: `error.h`
+//!
+//! <https://github.com/bminor/glibc/blob/master/misc/error.h>
+
+use crate::prelude::*;
+
+extern "C" {
+    pub static mut error_print_progname: Option<unsafe extern "C" fn()>;
+    pub static mut error_message_count: c_uint;
+    pub static mut error_one_per_line: c_int;
+
+    pub fn error(status: c_int, errnum: c_int, format: *const c_char, ...);
+    pub fn error_at_line(
+        status: c_int,
+        errnum: c_int,
+        fname: *const c_char,
+        lineno: c_uint,
+        format: *const c_char,
+        ...
+    );
+}
@@ -6,6 +6,27 @@
 //! This module structure is modeled after glibc's source tree. Its build system selects headers
 //! from different locations based on the platform, which we mimic here with reexports.
 
+/// Source directory: `argp/`
+///
+/// <https://github.com/bminor/glibc/tree/master/argp>
+mod argp {
+    pub(crate) mod argp_;
+}
+
+/// Source directory: `malloc/`
+///
+/// <https://github.com/bminor/glibc/tree/master/malloc>
+mod malloc {
+    pub(crate) mod obstack_;
+}
+
+/// Source directory: `misc/`
+///
+/// <https://github.com/bminor/glibc/tree/master/misc>
+mod misc {
+    pub(crate) mod error_;
+}
+
 /// Source directory: `posix/`
 ///
 /// <https://github.com/bminor/glibc/tree/master/posix>
@@ -13,6 +34,13 @@ mod posix {
     pub(crate) mod unistd;
 }
 
+/// Source directory: `stdio-common/`
+///
+/// <https://github.com/bminor/glibc/tree/master/stdio-common>
+mod stdio_common {
+    pub(crate) mod printf;
+}
+
 /// Source directory: `sysdeps/`
 ///
 /// <https://github.com/bminor/glibc/tree/master/sysdeps>
@@ -23,7 +51,11 @@ mod sysdeps {
     pub(crate) mod unix;
 }
 
+pub(crate) use argp::*;
+pub(crate) use malloc::*;
+pub(crate) use misc::*;
 pub(crate) use posix::*;
+pub(crate) use stdio_common::*;
 // FIXME(pthread): eventually all platforms should use this module
 #[cfg(target_os = "linux")]
 pub(crate) use sysdeps::nptl::*;
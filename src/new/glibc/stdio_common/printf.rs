@@ -0,0 +1,67 @@
+//! Header: `printf.h`
+//!
+//! <https://github.com/bminor/glibc/blob/master/stdio-common/printf.h>
+
+use crate::prelude::*;
+use crate::FILE;
+
+s! {
+    pub struct printf_info {
+        pub prec: c_int,
+        pub width: c_int,
+        pub spec: crate::wchar_t,
+        /// This contains the 1-bit flags `is_long_double`, `is_short`, `is_long`, `alt`,
+        /// `space`, `left`, `showsign`, `group`, `extra`, `is_char`, `wide`, `i18n` and
+        /// `is_binary128`, in that order starting from the least significant bit.
+        pub flags: c_ushort,
+        pub user: c_ushort,
+        pub pad: crate::wchar_t,
+    }
+}
+
+pub type printf_function = unsafe extern "C" fn(
+    stream: *mut FILE,
+    info: *const printf_info,
+    args: *const *const c_void,
+) -> c_int;
+pub type printf_arginfo_size_function = unsafe extern "C" fn(
+    info: *const printf_info,
+    n: size_t,
+    argtypes: *mut c_int,
+    size: *mut c_int,
+) -> c_int;
+
+// Basic types returned by `parse_printf_format`
+pub const PA_INT: c_int = 0;
+pub const PA_CHAR: c_int = 1;
+pub const PA_WCHAR: c_int = 2;
+pub const PA_STRING: c_int = 3;
+pub const PA_WSTRING: c_int = 4;
+pub const PA_POINTER: c_int = 5;
+pub const PA_FLOAT: c_int = 6;
+pub const PA_DOUBLE: c_int = 7;
+pub const PA_LAST: c_int = 8;
+
+// Flags that may be OR'd into the basic types
+pub const PA_FLAG_MASK: c_int = 0xff00;
+pub const PA_FLAG_LONG_LONG: c_int = 1 << 8;
+pub const PA_FLAG_LONG_DOUBLE: c_int = PA_FLAG_LONG_LONG;
+pub const PA_FLAG_LONG: c_int = 1 << 9;
+pub const PA_FLAG_SHORT: c_int = 1 << 10;
+pub const PA_FLAG_PTR: c_int = 1 << 11;
+
+extern "C" {
+    pub fn register_printf_specifier(
+        spec: c_int,
+        func: Option<printf_function>,
+        arginfo: Option<printf_arginfo_size_function>,
+    ) -> c_int;
+    pub fn register_printf_modifier(str: *const crate::wchar_t) -> c_int;
+    pub fn parse_printf_format(fmt: *const c_char, n: size_t, argtypes: *mut c_int) -> size_t;
+    pub fn printf_size(
+        fp: *mut FILE,
+        info: *const printf_info,
+        args: *const *const c_void,
+    ) -> c_int;
+    pub fn printf_size_info(info: *const printf_info, n: size_t, argtypes: *mut c_int) -> c_int;
+}
@@ -0,0 +1,69 @@
+//! Header: `uapi/linux/blkzoned.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+c_enum! {
+    pub enum blk_zone_type {
+        pub BLK_ZONE_TYPE_CONVENTIONAL = 0x1,
+        pub BLK_ZONE_TYPE_SEQWRITE_REQ = 0x2,
+        pub BLK_ZONE_TYPE_SEQWRITE_PREF = 0x3,
+    }
+
+    pub enum blk_zone_cond {
+        pub BLK_ZONE_COND_NOT_WP = 0x0,
+        pub BLK_ZONE_COND_EMPTY = 0x1,
+        pub BLK_ZONE_COND_IMP_OPEN = 0x2,
+        pub BLK_ZONE_COND_EXP_OPEN = 0x3,
+        pub BLK_ZONE_COND_CLOSED = 0x4,
+        pub BLK_ZONE_COND_READONLY = 0xD,
+        pub BLK_ZONE_COND_FULL = 0xE,
+        pub BLK_ZONE_COND_OFFLINE = 0xF,
+    }
+
+    pub enum blk_zone_report_flags {
+        pub BLK_ZONE_REP_CAPACITY = 1 << 0,
+    }
+}
+
+s! {
+    pub struct blk_zone {
+        pub start: crate::__u64,
+        pub len: crate::__u64,
+        pub wp: crate::__u64,
+        pub type_: crate::__u8,
+        pub cond: crate::__u8,
+        pub non_seq: crate::__u8,
+        pub reset: crate::__u8,
+        resv: Padding<[crate::__u8; 4]>,
+        pub capacity: crate::__u64,
+        reserved: Padding<[crate::__u8; 24]>,
+    }
+
+    pub struct blk_zone_report {
+        pub sector: crate::__u64,
+        pub nr_zones: crate::__u32,
+        pub flags: crate::__u32,
+        pub zones: [blk_zone; 0],
+    }
+
+    pub struct blk_zone_range {
+        pub sector: crate::__u64,
+        pub nr_sectors: crate::__u64,
+    }
+}
+
+const BLKZONED_IOC_MAGIC: c_uint = 0x12;
+
+pub const BLKREPORTZONE: Ioctl = _IOWR::<blk_zone_report>(BLKZONED_IOC_MAGIC, 130);
+pub const BLKRESETZONE: Ioctl = _IOW::<blk_zone_range>(BLKZONED_IOC_MAGIC, 131);
+pub const BLKGETZONESZ: Ioctl = _IOR::<crate::__u32>(BLKZONED_IOC_MAGIC, 132);
+pub const BLKGETNRZONES: Ioctl = _IOR::<crate::__u32>(BLKZONED_IOC_MAGIC, 133);
+pub const BLKOPENZONE: Ioctl = _IOW::<blk_zone_range>(BLKZONED_IOC_MAGIC, 134);
+pub const BLKCLOSEZONE: Ioctl = _IOW::<blk_zone_range>(BLKZONED_IOC_MAGIC, 135);
+pub const BLKFINISHZONE: Ioctl = _IOW::<blk_zone_range>(BLKZONED_IOC_MAGIC, 136);
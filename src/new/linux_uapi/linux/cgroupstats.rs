@@ -0,0 +1,35 @@
+//! Header: `uapi/linux/cgroupstats.h`
+
+use crate::prelude::*;
+
+s! {
+    pub struct cgroupstats {
+        pub nr_sleeping: crate::__u64,
+        pub nr_running: crate::__u64,
+        pub nr_stopped: crate::__u64,
+        pub nr_uninterruptible: crate::__u64,
+        pub nr_io_wait: crate::__u64,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        // Continues after `__TASKSTATS_CMD_MAX`
+        pub CGROUPSTATS_CMD_UNSPEC = 3,
+        pub CGROUPSTATS_CMD_GET,
+        pub CGROUPSTATS_CMD_NEW,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CGROUPSTATS_TYPE_UNSPEC = 0,
+        pub CGROUPSTATS_TYPE_CGROUP_STATS,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CGROUPSTATS_CMD_ATTR_UNSPEC = 0,
+        pub CGROUPSTATS_CMD_ATTR_FD,
+    }
+}
@@ -0,0 +1,134 @@
+//! Header: `uapi/linux/dm-ioctl.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOWR,
+};
+
+pub const DM_MAX_TYPE_NAME: usize = 16;
+pub const DM_NAME_LEN: usize = 128;
+pub const DM_UUID_LEN: usize = 129;
+
+s! {
+    pub struct dm_ioctl {
+        pub version: [crate::__u32; 3],
+        pub data_size: crate::__u32,
+        pub data_start: crate::__u32,
+        pub target_count: crate::__u32,
+        pub open_count: crate::__s32,
+        pub flags: crate::__u32,
+        pub event_nr: crate::__u32,
+        padding: Padding<crate::__u32>,
+        pub dev: crate::__u64,
+        pub name: [c_char; DM_NAME_LEN],
+        pub uuid: [c_char; DM_UUID_LEN],
+        pub data: [c_char; 7],
+    }
+
+    pub struct dm_target_spec {
+        pub sector_start: crate::__u64,
+        pub length: crate::__u64,
+        pub status: crate::__s32,
+        pub next: crate::__u32,
+        pub target_type: [c_char; DM_MAX_TYPE_NAME],
+    }
+
+    pub struct dm_target_deps {
+        pub count: crate::__u32,
+        padding: Padding<crate::__u32>,
+        pub dev: [crate::__u64; 0],
+    }
+
+    pub struct dm_name_list {
+        pub dev: crate::__u64,
+        pub next: crate::__u32,
+        pub name: [c_char; 0],
+    }
+}
+
+pub const DM_NAME_LIST_FLAG_HAS_UUID: c_uint = 1;
+pub const DM_NAME_LIST_FLAG_DOESNT_HAVE_UUID: c_uint = 2;
+
+s! {
+    pub struct dm_target_versions {
+        pub next: crate::__u32,
+        pub version: [crate::__u32; 3],
+        pub name: [c_char; 0],
+    }
+
+    pub struct dm_target_msg {
+        pub sector: crate::__u64,
+        pub message: [c_char; 0],
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub DM_VERSION_CMD = 0,
+        pub DM_REMOVE_ALL_CMD,
+        pub DM_LIST_DEVICES_CMD,
+        pub DM_DEV_CREATE_CMD,
+        pub DM_DEV_REMOVE_CMD,
+        pub DM_DEV_RENAME_CMD,
+        pub DM_DEV_SUSPEND_CMD,
+        pub DM_DEV_STATUS_CMD,
+        pub DM_DEV_WAIT_CMD,
+        pub DM_TABLE_LOAD_CMD,
+        pub DM_TABLE_CLEAR_CMD,
+        pub DM_TABLE_DEPS_CMD,
+        pub DM_TABLE_STATUS_CMD,
+        pub DM_LIST_VERSIONS_CMD,
+        pub DM_TARGET_MSG_CMD,
+        pub DM_DEV_SET_GEOMETRY_CMD,
+        pub DM_DEV_ARM_POLL_CMD,
+        pub DM_GET_TARGET_VERSION_CMD,
+    }
+}
+
+pub const DM_IOCTL: c_uint = 0xfd;
+
+pub const DM_VERSION: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_VERSION_CMD);
+pub const DM_REMOVE_ALL: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_REMOVE_ALL_CMD);
+pub const DM_LIST_DEVICES: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_LIST_DEVICES_CMD);
+
+pub const DM_DEV_CREATE: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_CREATE_CMD);
+pub const DM_DEV_REMOVE: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_REMOVE_CMD);
+pub const DM_DEV_RENAME: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_RENAME_CMD);
+pub const DM_DEV_SUSPEND: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_SUSPEND_CMD);
+pub const DM_DEV_STATUS: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_STATUS_CMD);
+pub const DM_DEV_WAIT: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_WAIT_CMD);
+pub const DM_DEV_ARM_POLL: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_ARM_POLL_CMD);
+
+pub const DM_TABLE_LOAD: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_TABLE_LOAD_CMD);
+pub const DM_TABLE_CLEAR: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_TABLE_CLEAR_CMD);
+pub const DM_TABLE_DEPS: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_TABLE_DEPS_CMD);
+pub const DM_TABLE_STATUS: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_TABLE_STATUS_CMD);
+
+pub const DM_LIST_VERSIONS: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_LIST_VERSIONS_CMD);
+pub const DM_GET_TARGET_VERSION: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_GET_TARGET_VERSION_CMD);
+
+pub const DM_TARGET_MSG: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_TARGET_MSG_CMD);
+pub const DM_DEV_SET_GEOMETRY: Ioctl = _IOWR::<dm_ioctl>(DM_IOCTL, DM_DEV_SET_GEOMETRY_CMD);
+
+pub const DM_VERSION_MAJOR: c_uint = 4;
+
+pub const DM_READONLY_FLAG: c_uint = 1 << 0;
+pub const DM_SUSPEND_FLAG: c_uint = 1 << 1;
+pub const DM_PERSISTENT_DEV_FLAG: c_uint = 1 << 3;
+pub const DM_STATUS_TABLE_FLAG: c_uint = 1 << 4;
+pub const DM_ACTIVE_PRESENT_FLAG: c_uint = 1 << 5;
+pub const DM_INACTIVE_PRESENT_FLAG: c_uint = 1 << 6;
+pub const DM_BUFFER_FULL_FLAG: c_uint = 1 << 8;
+pub const DM_SKIP_BDGET_FLAG: c_uint = 1 << 9;
+pub const DM_SKIP_LOCKFS_FLAG: c_uint = 1 << 10;
+pub const DM_NOFLUSH_FLAG: c_uint = 1 << 11;
+pub const DM_QUERY_INACTIVE_TABLE_FLAG: c_uint = 1 << 12;
+pub const DM_UEVENT_GENERATED_FLAG: c_uint = 1 << 13;
+pub const DM_UUID_FLAG: c_uint = 1 << 14;
+pub const DM_SECURE_DATA_FLAG: c_uint = 1 << 15;
+pub const DM_DATA_OUT_FLAG: c_uint = 1 << 16;
+pub const DM_DEFERRED_REMOVE: c_uint = 1 << 17;
+pub const DM_INTERNAL_SUSPEND_FLAG: c_uint = 1 << 18;
+pub const DM_IMA_MEASUREMENT_FLAG: c_uint = 1 << 19;
@@ -0,0 +1,163 @@
+//! Header: `uapi/linux/fuse.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOR,
+};
+
+/// Version number of this interface.
+pub const FUSE_KERNEL_VERSION: u32 = 7;
+
+/// Minor version number of this interface.
+///
+/// This symbol is prone to change across releases upstream.
+/// See the [usage guidelines](crate#usage-guidelines) for details.
+pub const FUSE_KERNEL_MINOR_VERSION: u32 = 41;
+
+/// The node ID of the root inode.
+pub const FUSE_ROOT_ID: u64 = 1;
+
+// Init request/reply flags
+pub const FUSE_ASYNC_READ: u32 = 1 << 0;
+pub const FUSE_POSIX_LOCKS: u32 = 1 << 1;
+pub const FUSE_FILE_OPS: u32 = 1 << 2;
+pub const FUSE_ATOMIC_O_TRUNC: u32 = 1 << 3;
+pub const FUSE_EXPORT_SUPPORT: u32 = 1 << 4;
+pub const FUSE_BIG_WRITES: u32 = 1 << 5;
+pub const FUSE_DONT_MASK: u32 = 1 << 6;
+pub const FUSE_SPLICE_WRITE: u32 = 1 << 7;
+pub const FUSE_SPLICE_MOVE: u32 = 1 << 8;
+pub const FUSE_SPLICE_READ: u32 = 1 << 9;
+pub const FUSE_FLOCK_LOCKS: u32 = 1 << 10;
+pub const FUSE_HAS_IOCTL_DIR: u32 = 1 << 11;
+pub const FUSE_AUTO_INVAL_DATA: u32 = 1 << 12;
+pub const FUSE_DO_READDIRPLUS: u32 = 1 << 13;
+pub const FUSE_READDIRPLUS_AUTO: u32 = 1 << 14;
+pub const FUSE_ASYNC_DIO: u32 = 1 << 15;
+pub const FUSE_WRITEBACK_CACHE: u32 = 1 << 16;
+pub const FUSE_NO_OPEN_SUPPORT: u32 = 1 << 17;
+pub const FUSE_PARALLEL_DIROPS: u32 = 1 << 18;
+pub const FUSE_HANDLE_KILLPRIV: u32 = 1 << 19;
+pub const FUSE_POSIX_ACL: u32 = 1 << 20;
+pub const FUSE_ABORT_ERROR: u32 = 1 << 21;
+pub const FUSE_MAX_PAGES: u32 = 1 << 22;
+pub const FUSE_CACHE_SYMLINKS: u32 = 1 << 23;
+pub const FUSE_NO_OPENDIR_SUPPORT: u32 = 1 << 24;
+pub const FUSE_EXPLICIT_INVAL_DATA: u32 = 1 << 25;
+pub const FUSE_MAP_ALIGNMENT: u32 = 1 << 26;
+pub const FUSE_SUBMOUNTS: u32 = 1 << 27;
+pub const FUSE_HANDLE_KILLPRIV_V2: u32 = 1 << 28;
+pub const FUSE_SETXATTR_EXT: u32 = 1 << 29;
+pub const FUSE_INIT_EXT: u32 = 1 << 30;
+pub const FUSE_INIT_RESERVED: u32 = 1 << 31;
+
+c_enum! {
+    pub enum fuse_opcode {
+        pub FUSE_LOOKUP = 1,
+        pub FUSE_FORGET = 2,
+        pub FUSE_GETATTR = 3,
+        pub FUSE_SETATTR = 4,
+        pub FUSE_READLINK = 5,
+        pub FUSE_SYMLINK = 6,
+        pub FUSE_MKNOD = 8,
+        pub FUSE_MKDIR = 9,
+        pub FUSE_UNLINK = 10,
+        pub FUSE_RMDIR = 11,
+        pub FUSE_RENAME = 12,
+        pub FUSE_LINK = 13,
+        pub FUSE_OPEN = 14,
+        pub FUSE_READ = 15,
+        pub FUSE_WRITE = 16,
+        pub FUSE_STATFS = 17,
+        pub FUSE_RELEASE = 18,
+        pub FUSE_FSYNC = 20,
+        pub FUSE_SETXATTR = 21,
+        pub FUSE_GETXATTR = 22,
+        pub FUSE_LISTXATTR = 23,
+        pub FUSE_REMOVEXATTR = 24,
+        pub FUSE_FLUSH = 25,
+        pub FUSE_INIT = 26,
+        pub FUSE_OPENDIR = 27,
+        pub FUSE_READDIR = 28,
+        pub FUSE_RELEASEDIR = 29,
+        pub FUSE_FSYNCDIR = 30,
+        pub FUSE_GETLK = 31,
+        pub FUSE_SETLK = 32,
+        pub FUSE_SETLKW = 33,
+        pub FUSE_ACCESS = 34,
+        pub FUSE_CREATE = 35,
+        pub FUSE_INTERRUPT = 36,
+        pub FUSE_BMAP = 37,
+        pub FUSE_DESTROY = 38,
+        pub FUSE_IOCTL = 39,
+        pub FUSE_POLL = 40,
+        pub FUSE_NOTIFY_REPLY = 41,
+        pub FUSE_BATCH_FORGET = 42,
+        pub FUSE_FALLOCATE = 43,
+        pub FUSE_READDIRPLUS = 44,
+        pub FUSE_RENAME2 = 45,
+        pub FUSE_LSEEK = 46,
+        pub FUSE_COPY_FILE_RANGE = 47,
+        pub FUSE_SETUPMAPPING = 48,
+        pub FUSE_REMOVEMAPPING = 49,
+        pub FUSE_SYNCFS = 50,
+        pub FUSE_TMPFILE = 51,
+        pub FUSE_STATX = 52,
+
+        // CUSE specific operations
+        pub CUSE_INIT = 4096,
+    }
+}
+
+/// The read buffer is required to be at least 8k, but may be much larger.
+pub const FUSE_MIN_READ_BUFFER: c_uint = 8192;
+
+s! {
+    pub struct fuse_init_in {
+        pub major: u32,
+        pub minor: u32,
+        pub max_readahead: u32,
+        pub flags: u32,
+        pub flags2: u32,
+        unused: Padding<[u32; 11]>,
+    }
+
+    pub struct fuse_init_out {
+        pub major: u32,
+        pub minor: u32,
+        pub max_readahead: u32,
+        pub flags: u32,
+        pub max_background: u16,
+        pub congestion_threshold: u16,
+        pub max_write: u32,
+        pub time_gran: u32,
+        pub max_pages: u16,
+        pub map_alignment: u16,
+        pub flags2: u32,
+        pub max_stack_depth: u32,
+        unused: Padding<[u32; 6]>,
+    }
+
+    pub struct fuse_in_header {
+        pub len: u32,
+        pub opcode: u32,
+        pub unique: u64,
+        pub nodeid: u64,
+        pub uid: u32,
+        pub gid: u32,
+        pub pid: u32,
+        pub total_extlen: u16,
+        padding: Padding<u16>,
+    }
+
+    pub struct fuse_out_header {
+        pub len: u32,
+        pub error: i32,
+        pub unique: u64,
+    }
+}
+
+// Device ioctls
+pub const FUSE_DEV_IOC_MAGIC: c_uint = 229;
+pub const FUSE_DEV_IOC_CLONE: Ioctl = _IOR::<u32>(FUSE_DEV_IOC_MAGIC, 0);
@@ -0,0 +1,152 @@
+//! Header: `uapi/linux/gpio.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IOR,
+    _IOWR,
+};
+
+pub const GPIO_MAX_NAME_SIZE: usize = 32;
+
+s! {
+    pub struct gpiochip_info {
+        pub name: [c_char; GPIO_MAX_NAME_SIZE],
+        pub label: [c_char; GPIO_MAX_NAME_SIZE],
+        pub lines: crate::__u32,
+    }
+}
+
+pub const GPIO_V2_LINES_MAX: usize = 64;
+pub const GPIO_V2_LINE_NUM_ATTRS_MAX: usize = 10;
+
+c_enum! {
+    pub enum gpio_v2_line_flag {
+        pub GPIO_V2_LINE_FLAG_USED = 1 << 0,
+        pub GPIO_V2_LINE_FLAG_ACTIVE_LOW = 1 << 1,
+        pub GPIO_V2_LINE_FLAG_INPUT = 1 << 2,
+        pub GPIO_V2_LINE_FLAG_OUTPUT = 1 << 3,
+        pub GPIO_V2_LINE_FLAG_EDGE_RISING = 1 << 4,
+        pub GPIO_V2_LINE_FLAG_EDGE_FALLING = 1 << 5,
+        pub GPIO_V2_LINE_FLAG_OPEN_DRAIN = 1 << 6,
+        pub GPIO_V2_LINE_FLAG_OPEN_SOURCE = 1 << 7,
+        pub GPIO_V2_LINE_FLAG_BIAS_PULL_UP = 1 << 8,
+        pub GPIO_V2_LINE_FLAG_BIAS_PULL_DOWN = 1 << 9,
+        pub GPIO_V2_LINE_FLAG_BIAS_DISABLED = 1 << 10,
+        pub GPIO_V2_LINE_FLAG_EVENT_CLOCK_REALTIME = 1 << 11,
+        pub GPIO_V2_LINE_FLAG_EVENT_CLOCK_HTE = 1 << 12,
+    }
+}
+
+s! {
+    #[repr(align(8))]
+    pub struct gpio_v2_line_values {
+        pub bits: crate::__u64,
+        pub mask: crate::__u64,
+    }
+}
+
+c_enum! {
+    pub enum gpio_v2_line_attr_id {
+        pub GPIO_V2_LINE_ATTR_ID_FLAGS = 1,
+        pub GPIO_V2_LINE_ATTR_ID_OUTPUT_VALUES = 2,
+        pub GPIO_V2_LINE_ATTR_ID_DEBOUNCE = 3,
+    }
+}
+
+s_no_extra_traits! {
+    #[repr(align(8))]
+    pub union __c_anonymous_gpio_v2_line_attribute_1 {
+        pub flags: crate::__u64,
+        pub values: crate::__u64,
+        pub debounce_period_us: crate::__u32,
+    }
+
+    pub struct gpio_v2_line_attribute {
+        pub id: crate::__u32,
+        pub padding: crate::__u32,
+        pub anonymous_1: __c_anonymous_gpio_v2_line_attribute_1,
+    }
+
+    #[repr(align(8))]
+    pub struct gpio_v2_line_config_attribute {
+        pub attr: gpio_v2_line_attribute,
+        pub mask: crate::__u64,
+    }
+
+    #[repr(align(8))]
+    pub struct gpio_v2_line_config {
+        pub flags: crate::__u64,
+        pub num_attrs: crate::__u32,
+        pub padding: [crate::__u32; 5],
+        pub attrs: [gpio_v2_line_config_attribute; GPIO_V2_LINE_NUM_ATTRS_MAX],
+    }
+
+    pub struct gpio_v2_line_request {
+        pub offsets: [crate::__u32; GPIO_V2_LINES_MAX],
+        pub consumer: [c_char; GPIO_MAX_NAME_SIZE],
+        pub config: gpio_v2_line_config,
+        pub num_lines: crate::__u32,
+        pub event_buffer_size: crate::__u32,
+        pub padding: [crate::__u32; 5],
+        pub fd: crate::__s32,
+    }
+
+    #[repr(align(8))]
+    pub struct gpio_v2_line_info {
+        pub name: [c_char; GPIO_MAX_NAME_SIZE],
+        pub consumer: [c_char; GPIO_MAX_NAME_SIZE],
+        pub offset: crate::__u32,
+        pub num_attrs: crate::__u32,
+        pub flags: crate::__u64,
+        pub attrs: [gpio_v2_line_attribute; GPIO_V2_LINE_NUM_ATTRS_MAX],
+        pub padding: [crate::__u32; 4],
+    }
+}
+
+c_enum! {
+    pub enum gpio_v2_line_changed_type {
+        pub GPIO_V2_LINE_CHANGED_REQUESTED = 1,
+        pub GPIO_V2_LINE_CHANGED_RELEASED = 2,
+        pub GPIO_V2_LINE_CHANGED_CONFIG = 3,
+    }
+}
+
+s_no_extra_traits! {
+    #[repr(align(8))]
+    pub struct gpio_v2_line_info_changed {
+        pub info: gpio_v2_line_info,
+        pub timestamp_ns: crate::__u64,
+        pub event_type: crate::__u32,
+        pub padding: [crate::__u32; 5],
+    }
+}
+
+c_enum! {
+    pub enum gpio_v2_line_event_id {
+        pub GPIO_V2_LINE_EVENT_RISING_EDGE = 1,
+        pub GPIO_V2_LINE_EVENT_FALLING_EDGE = 2,
+    }
+}
+
+s! {
+    #[repr(align(8))]
+    pub struct gpio_v2_line_event {
+        pub timestamp_ns: crate::__u64,
+        pub id: crate::__u32,
+        pub offset: crate::__u32,
+        pub seqno: crate::__u32,
+        pub line_seqno: crate::__u32,
+        pub padding: [crate::__u32; 6],
+    }
+}
+
+pub const GPIO_GET_CHIPINFO_IOCTL: Ioctl = _IOR::<gpiochip_info>(0xB4, 0x01);
+pub const GPIO_GET_LINEINFO_UNWATCH_IOCTL: Ioctl = _IOWR::<crate::__u32>(0xB4, 0x0C);
+
+pub const GPIO_V2_GET_LINEINFO_IOCTL: Ioctl = _IOWR::<gpio_v2_line_info>(0xB4, 0x05);
+pub const GPIO_V2_GET_LINEINFO_WATCH_IOCTL: Ioctl = _IOWR::<gpio_v2_line_info>(0xB4, 0x06);
+pub const GPIO_V2_GET_LINE_IOCTL: Ioctl = _IOWR::<gpio_v2_line_request>(0xB4, 0x07);
+pub const GPIO_V2_LINE_SET_CONFIG_IOCTL: Ioctl = _IOWR::<gpio_v2_line_config>(0xB4, 0x0D);
+pub const GPIO_V2_LINE_GET_VALUES_IOCTL: Ioctl = _IOWR::<gpio_v2_line_values>(0xB4, 0x0E);
+pub const GPIO_V2_LINE_SET_VALUES_IOCTL: Ioctl = _IOWR::<gpio_v2_line_values>(0xB4, 0x0F);
@@ -0,0 +1,24 @@
+//! Header: `uapi/linux/kcmp.h`
+
+c_enum! {
+    pub enum kcmp_type {
+        pub KCMP_FILE,
+        pub KCMP_VM,
+        pub KCMP_FILES,
+        pub KCMP_FS,
+        pub KCMP_SIGHAND,
+        pub KCMP_IO,
+        pub KCMP_SYSVSEM,
+        pub KCMP_EPOLL_TFD,
+
+        pub KCMP_TYPES,
+    }
+}
+
+s! {
+    pub struct kcmp_epoll_slot {
+        pub efd: crate::__u32,
+        pub tfd: crate::__u32,
+        pub toff: crate::__u32,
+    }
+}
@@ -2,16 +2,25 @@
 //!
 //! <https://github.com/torvalds/linux/tree/master/include/uapi/linux>
 
+pub(crate) mod blkzoned;
 pub(crate) mod can;
+pub(crate) mod cgroupstats;
+pub(crate) mod dm_ioctl;
+pub(crate) mod fuse;
 pub(crate) mod futex;
+pub(crate) mod gpio;
 pub(crate) mod if_addr;
 pub(crate) mod if_link;
 pub(crate) mod if_packet;
+pub(crate) mod kcmp;
 pub(crate) mod keyctl;
 pub(crate) mod membarrier;
 pub(crate) mod mount;
 pub(crate) mod netlink;
 pub(crate) mod pidfd;
+pub(crate) mod random;
 pub(crate) mod sctp;
+pub(crate) mod sync_file;
+pub(crate) mod taskstats;
 pub(crate) mod tls;
 pub(crate) mod types;
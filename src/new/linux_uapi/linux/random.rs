@@ -0,0 +1,24 @@
+//! Header: `uapi/linux/random.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+};
+
+pub const RNDGETENTCNT: Ioctl = _IOR::<c_int>(b'R' as u32, 0x00);
+pub const RNDADDTOENTCNT: Ioctl = _IOW::<c_int>(b'R' as u32, 0x01);
+pub const RNDGETPOOL: Ioctl = _IOR::<[c_int; 2]>(b'R' as u32, 0x02);
+pub const RNDADDENTROPY: Ioctl = _IOW::<[c_int; 2]>(b'R' as u32, 0x03);
+pub const RNDZAPENTCNT: Ioctl = _IO(b'R' as u32, 0x04);
+pub const RNDCLEARPOOL: Ioctl = _IO(b'R' as u32, 0x06);
+pub const RNDRESEEDCRNG: Ioctl = _IO(b'R' as u32, 0x07);
+
+s! {
+    pub struct rand_pool_info {
+        pub entropy_count: c_int,
+        pub buf_size: c_int,
+    }
+}
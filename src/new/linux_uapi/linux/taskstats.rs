@@ -0,0 +1,105 @@
+//! Header: `uapi/linux/taskstats.h`
+
+use crate::prelude::*;
+
+/// This symbol is prone to change across releases upstream.
+/// See the [usage guidelines](crate#usage-guidelines) for details.
+pub const TASKSTATS_VERSION: c_int = 13;
+pub const TS_COMM_LEN: usize = 32;
+
+s! {
+    // The kernel copies at most `sizeof(struct taskstats)` of its own version, so this
+    // struct only grows at the end; check `version` before reading newer fields.
+    #[repr(align(8))]
+    pub struct taskstats {
+        pub version: crate::__u16,
+        pub ac_exitcode: crate::__u32,
+        pub ac_flag: crate::__u8,
+        pub ac_nice: crate::__u8,
+        __pad0: Padding<[u8; 6]>,
+        pub cpu_count: crate::__u64,
+        pub cpu_delay_total: crate::__u64,
+        pub blkio_count: crate::__u64,
+        pub blkio_delay_total: crate::__u64,
+        pub swapin_count: crate::__u64,
+        pub swapin_delay_total: crate::__u64,
+        pub cpu_run_real_total: crate::__u64,
+        pub cpu_run_virtual_total: crate::__u64,
+        pub ac_comm: [c_char; TS_COMM_LEN],
+        pub ac_sched: crate::__u8,
+        pub ac_pad: [crate::__u8; 3],
+        __pad1: Padding<[u8; 4]>,
+        pub ac_uid: crate::__u32,
+        pub ac_gid: crate::__u32,
+        pub ac_pid: crate::__u32,
+        pub ac_ppid: crate::__u32,
+        pub ac_btime: crate::__u32,
+        __pad2: Padding<[u8; 4]>,
+        pub ac_etime: crate::__u64,
+        pub ac_utime: crate::__u64,
+        pub ac_stime: crate::__u64,
+        pub ac_minflt: crate::__u64,
+        pub ac_majflt: crate::__u64,
+        pub coremem: crate::__u64,
+        pub virtmem: crate::__u64,
+        pub hiwater_rss: crate::__u64,
+        pub hiwater_vm: crate::__u64,
+        pub read_char: crate::__u64,
+        pub write_char: crate::__u64,
+        pub read_syscalls: crate::__u64,
+        pub write_syscalls: crate::__u64,
+        pub read_bytes: crate::__u64,
+        pub write_bytes: crate::__u64,
+        pub cancelled_write_bytes: crate::__u64,
+        pub nvcsw: crate::__u64,
+        pub nivcsw: crate::__u64,
+        pub ac_utimescaled: crate::__u64,
+        pub ac_stimescaled: crate::__u64,
+        pub cpu_scaled_run_real_total: crate::__u64,
+        pub freepages_count: crate::__u64,
+        pub freepages_delay_total: crate::__u64,
+        pub thrashing_count: crate::__u64,
+        pub thrashing_delay_total: crate::__u64,
+        pub ac_btime64: crate::__u64,
+        pub compact_count: crate::__u64,
+        pub compact_delay_total: crate::__u64,
+        pub ac_tgid: crate::__u32,
+        __pad3: Padding<[u8; 4]>,
+        pub ac_tgetime: crate::__u64,
+        pub ac_exe_dev: crate::__u64,
+        pub ac_exe_inode: crate::__u64,
+        pub wpcopy_count: crate::__u64,
+        pub wpcopy_delay_total: crate::__u64,
+    }
+}
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub TASKSTATS_CMD_UNSPEC = 0,
+        pub TASKSTATS_CMD_GET,
+        pub TASKSTATS_CMD_NEW,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub TASKSTATS_TYPE_UNSPEC = 0,
+        pub TASKSTATS_TYPE_PID,
+        pub TASKSTATS_TYPE_TGID,
+        pub TASKSTATS_TYPE_STATS,
+        pub TASKSTATS_TYPE_AGGR_PID,
+        pub TASKSTATS_TYPE_AGGR_TGID,
+        pub TASKSTATS_TYPE_NULL,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub TASKSTATS_CMD_ATTR_UNSPEC = 0,
+        pub TASKSTATS_CMD_ATTR_PID,
+        pub TASKSTATS_CMD_ATTR_TGID,
+        pub TASKSTATS_CMD_ATTR_REGISTER_CPUMASK,
+        pub TASKSTATS_CMD_ATTR_DEREGISTER_CPUMASK,
+    }
+}
+
+pub const TASKSTATS_GENL_VERSION: c_int = 0x1;
@@ -6,6 +6,7 @@ use crate::prelude::*;
 /* Definitions from `asm/types.h` -> `asm-generic/types.h` -> `asm-generic/int-ll64.h` */
 
 pub type __u8 = c_uchar;
+pub type __s8 = crate::c_schar;
 
 pub type __u16 = c_ushort;
 pub type __s16 = c_short;
@@ -0,0 +1,6 @@
+//! Directory: `mtd/`
+//!
+//! <https://github.com/torvalds/linux/tree/master/include/uapi/mtd>
+
+pub(crate) mod mtd_abi;
+pub(crate) mod ubi_user;
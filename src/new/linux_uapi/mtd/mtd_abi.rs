@@ -0,0 +1,197 @@
+//! Header: `uapi/mtd/mtd-abi.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+    _IOWR,
+};
+
+s! {
+    pub struct erase_info_user {
+        pub start: crate::__u32,
+        pub length: crate::__u32,
+    }
+
+    pub struct erase_info_user64 {
+        pub start: crate::__u64,
+        pub length: crate::__u64,
+    }
+
+    pub struct mtd_oob_buf {
+        pub start: crate::__u32,
+        pub length: crate::__u32,
+        pub ptr: *mut c_uchar,
+    }
+
+    pub struct mtd_oob_buf64 {
+        pub start: crate::__u64,
+        pub pad: crate::__u32,
+        pub length: crate::__u32,
+        pub usr_ptr: crate::__u64,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub MTD_OPS_PLACE_OOB = 0,
+        pub MTD_OPS_AUTO_OOB = 1,
+        pub MTD_OPS_RAW = 2,
+    }
+}
+
+s! {
+    pub struct mtd_write_req {
+        pub start: crate::__u64,
+        pub len: crate::__u64,
+        pub ooblen: crate::__u64,
+        pub usr_data: crate::__u64,
+        pub usr_oob: crate::__u64,
+        pub mode: crate::__u8,
+        pub padding: [crate::__u8; 7],
+    }
+
+    pub struct mtd_read_req_ecc_stats {
+        pub uncorrectable_errors: crate::__u32,
+        pub corrected_bitflips: crate::__u32,
+        pub max_bitflips: crate::__u32,
+    }
+
+    pub struct mtd_read_req {
+        pub start: crate::__u64,
+        pub len: crate::__u64,
+        pub ooblen: crate::__u64,
+        pub usr_data: crate::__u64,
+        pub usr_oob: crate::__u64,
+        pub mode: crate::__u8,
+        pub padding: [crate::__u8; 7],
+        pub ecc_stats: mtd_read_req_ecc_stats,
+    }
+}
+
+pub const MTD_ABSENT: c_uint = 0;
+pub const MTD_RAM: c_uint = 1;
+pub const MTD_ROM: c_uint = 2;
+pub const MTD_NORFLASH: c_uint = 3;
+pub const MTD_NANDFLASH: c_uint = 4;
+pub const MTD_DATAFLASH: c_uint = 6;
+pub const MTD_UBIVOLUME: c_uint = 7;
+pub const MTD_MLCNANDFLASH: c_uint = 8;
+
+pub const MTD_WRITEABLE: c_uint = 0x400;
+pub const MTD_BIT_WRITEABLE: c_uint = 0x800;
+pub const MTD_NO_ERASE: c_uint = 0x1000;
+pub const MTD_POWERUP_LOCK: c_uint = 0x2000;
+pub const MTD_SLC_ON_MLC_EMULATION: c_uint = 0x4000;
+
+pub const MTD_CAP_ROM: c_uint = 0;
+pub const MTD_CAP_RAM: c_uint = MTD_WRITEABLE | MTD_BIT_WRITEABLE | MTD_NO_ERASE;
+pub const MTD_CAP_NORFLASH: c_uint = MTD_WRITEABLE | MTD_BIT_WRITEABLE;
+pub const MTD_CAP_NANDFLASH: c_uint = MTD_WRITEABLE;
+pub const MTD_CAP_NVRAM: c_uint = MTD_WRITEABLE | MTD_BIT_WRITEABLE | MTD_NO_ERASE;
+
+pub const MTD_NANDECC_OFF: c_uint = 0;
+pub const MTD_NANDECC_PLACE: c_uint = 1;
+pub const MTD_NANDECC_AUTOPLACE: c_uint = 2;
+pub const MTD_NANDECC_PLACEONLY: c_uint = 3;
+pub const MTD_NANDECC_AUTOPL_USR: c_uint = 4;
+
+pub const MTD_OTP_OFF: c_uint = 0;
+pub const MTD_OTP_FACTORY: c_uint = 1;
+pub const MTD_OTP_USER: c_uint = 2;
+
+s! {
+    pub struct mtd_info_user {
+        pub type_: crate::__u8,
+        pub flags: crate::__u32,
+        pub size: crate::__u32,
+        pub erasesize: crate::__u32,
+        pub writesize: crate::__u32,
+        pub oobsize: crate::__u32,
+        pub padding: crate::__u64,
+    }
+
+    pub struct region_info_user {
+        pub offset: crate::__u32,
+        pub erasesize: crate::__u32,
+        pub numblocks: crate::__u32,
+        pub regionindex: crate::__u32,
+    }
+
+    pub struct otp_info {
+        pub start: crate::__u32,
+        pub length: crate::__u32,
+        pub locked: crate::__u32,
+    }
+}
+
+pub const MEMGETINFO: Ioctl = _IOR::<mtd_info_user>(b'M' as u32, 1);
+pub const MEMERASE: Ioctl = _IOW::<erase_info_user>(b'M' as u32, 2);
+pub const MEMWRITEOOB: Ioctl = _IOWR::<mtd_oob_buf>(b'M' as u32, 3);
+pub const MEMREADOOB: Ioctl = _IOWR::<mtd_oob_buf>(b'M' as u32, 4);
+pub const MEMLOCK: Ioctl = _IOW::<erase_info_user>(b'M' as u32, 5);
+pub const MEMUNLOCK: Ioctl = _IOW::<erase_info_user>(b'M' as u32, 6);
+pub const MEMGETREGIONCOUNT: Ioctl = _IOR::<c_int>(b'M' as u32, 7);
+pub const MEMGETREGIONINFO: Ioctl = _IOWR::<region_info_user>(b'M' as u32, 8);
+pub const MEMGETOOBSEL: Ioctl = _IOR::<nand_oobinfo>(b'M' as u32, 10);
+pub const MEMGETBADBLOCK: Ioctl = _IOW::<crate::loff_t>(b'M' as u32, 11);
+pub const MEMSETBADBLOCK: Ioctl = _IOW::<crate::loff_t>(b'M' as u32, 12);
+pub const OTPSELECT: Ioctl = _IOR::<c_int>(b'M' as u32, 13);
+pub const OTPGETREGIONCOUNT: Ioctl = _IOW::<c_int>(b'M' as u32, 14);
+pub const OTPGETREGIONINFO: Ioctl = _IOW::<otp_info>(b'M' as u32, 15);
+pub const OTPLOCK: Ioctl = _IOR::<otp_info>(b'M' as u32, 16);
+pub const ECCGETLAYOUT: Ioctl = _IOR::<nand_ecclayout_user>(b'M' as u32, 17);
+pub const ECCGETSTATS: Ioctl = _IOR::<mtd_ecc_stats>(b'M' as u32, 18);
+pub const MTDFILEMODE: Ioctl = _IO(b'M' as u32, 19);
+pub const MEMERASE64: Ioctl = _IOW::<erase_info_user64>(b'M' as u32, 20);
+pub const MEMWRITEOOB64: Ioctl = _IOWR::<mtd_oob_buf64>(b'M' as u32, 21);
+pub const MEMREADOOB64: Ioctl = _IOWR::<mtd_oob_buf64>(b'M' as u32, 22);
+pub const MEMISLOCKED: Ioctl = _IOR::<erase_info_user>(b'M' as u32, 23);
+pub const MEMWRITE: Ioctl = _IOWR::<mtd_write_req>(b'M' as u32, 24);
+pub const OTPERASE: Ioctl = _IOW::<otp_info>(b'M' as u32, 25);
+pub const MEMREAD: Ioctl = _IOWR::<mtd_read_req>(b'M' as u32, 26);
+
+s! {
+    pub struct nand_oobinfo {
+        pub useecc: crate::__u32,
+        pub eccbytes: crate::__u32,
+        pub oobfree: [[crate::__u32; 2]; 8],
+        pub eccpos: [crate::__u32; 32],
+    }
+
+    pub struct nand_oobfree {
+        pub offset: crate::__u32,
+        pub length: crate::__u32,
+    }
+}
+
+pub const MTD_MAX_OOBFREE_ENTRIES: usize = 8;
+pub const MTD_MAX_ECCPOS_ENTRIES: usize = 64;
+
+s! {
+    pub struct nand_ecclayout_user {
+        pub eccbytes: crate::__u32,
+        pub eccpos: [crate::__u32; MTD_MAX_ECCPOS_ENTRIES],
+        pub oobavail: crate::__u32,
+        pub oobfree: [nand_oobfree; MTD_MAX_OOBFREE_ENTRIES],
+    }
+
+    pub struct mtd_ecc_stats {
+        pub corrected: crate::__u32,
+        pub failed: crate::__u32,
+        pub badblocks: crate::__u32,
+        pub bbtblocks: crate::__u32,
+    }
+}
+
+c_enum! {
+    pub enum mtd_file_modes {
+        pub MTD_FILE_MODE_NORMAL = MTD_OTP_OFF,
+        pub MTD_FILE_MODE_OTP_FACTORY = MTD_OTP_FACTORY,
+        pub MTD_FILE_MODE_OTP_USER = MTD_OTP_USER,
+        pub MTD_FILE_MODE_RAW,
+    }
+}
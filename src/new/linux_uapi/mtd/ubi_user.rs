@@ -0,0 +1,139 @@
+//! Header: `uapi/mtd/ubi-user.h`
+
+use crate::prelude::*;
+use crate::{
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+};
+
+pub const UBI_VOL_NUM_AUTO: c_int = -1;
+pub const UBI_DEV_NUM_AUTO: c_int = -1;
+
+pub const UBI_MAX_VOLUME_NAME: usize = 127;
+
+pub const UBI_IOC_MAGIC: c_uint = b'o' as c_uint;
+
+pub const UBI_IOCMKVOL: Ioctl = _IOW::<ubi_mkvol_req>(UBI_IOC_MAGIC, 0);
+pub const UBI_IOCRMVOL: Ioctl = _IOW::<crate::__s32>(UBI_IOC_MAGIC, 1);
+pub const UBI_IOCRSVOL: Ioctl = _IOW::<ubi_rsvol_req>(UBI_IOC_MAGIC, 2);
+pub const UBI_IOCRNVOL: Ioctl = _IOW::<ubi_rnvol_req>(UBI_IOC_MAGIC, 3);
+pub const UBI_IOCRPEB: Ioctl = _IOW::<crate::__s32>(UBI_IOC_MAGIC, 4);
+pub const UBI_IOCSPEB: Ioctl = _IOW::<crate::__s32>(UBI_IOC_MAGIC, 5);
+
+pub const UBI_CTRL_IOC_MAGIC: c_uint = b'o' as c_uint;
+
+pub const UBI_IOCATT: Ioctl = _IOW::<ubi_attach_req>(UBI_CTRL_IOC_MAGIC, 64);
+pub const UBI_IOCDET: Ioctl = _IOW::<crate::__s32>(UBI_CTRL_IOC_MAGIC, 65);
+
+pub const UBI_VOL_IOC_MAGIC: c_uint = b'O' as c_uint;
+
+pub const UBI_IOCVOLUP: Ioctl = _IOW::<crate::__s64>(UBI_VOL_IOC_MAGIC, 0);
+pub const UBI_IOCEBER: Ioctl = _IOW::<crate::__s32>(UBI_VOL_IOC_MAGIC, 1);
+pub const UBI_IOCEBCH: Ioctl = _IOW::<crate::__s32>(UBI_VOL_IOC_MAGIC, 2);
+pub const UBI_IOCEBMAP: Ioctl = _IOW::<ubi_map_req>(UBI_VOL_IOC_MAGIC, 3);
+pub const UBI_IOCEBUNMAP: Ioctl = _IOW::<crate::__s32>(UBI_VOL_IOC_MAGIC, 4);
+pub const UBI_IOCEBISMAP: Ioctl = _IOR::<crate::__s32>(UBI_VOL_IOC_MAGIC, 5);
+pub const UBI_IOCSETVOLPROP: Ioctl = _IOW::<ubi_set_vol_prop_req>(UBI_VOL_IOC_MAGIC, 6);
+pub const UBI_IOCVOLCRBLK: Ioctl = _IOW::<ubi_blkcreate_req>(UBI_VOL_IOC_MAGIC, 7);
+pub const UBI_IOCVOLRMBLK: Ioctl = _IO(UBI_VOL_IOC_MAGIC, 8);
+
+pub const MAX_UBI_MTD_NAME_LEN: usize = 127;
+
+pub const UBI_MAX_RNVOL: usize = 32;
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub UBI_DYNAMIC_VOLUME = 3,
+        pub UBI_STATIC_VOLUME = 4,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub UBI_VOL_PROP_DIRECT_WRITE = 1,
+    }
+}
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub UBI_VOL_SKIP_CRC_CHECK_FLG = 0x1,
+    }
+}
+
+pub const UBI_VOL_VALID_FLGS: c_uint = UBI_VOL_SKIP_CRC_CHECK_FLG;
+
+s! {
+    pub struct ubi_attach_req {
+        pub ubi_num: crate::__s32,
+        pub mtd_num: crate::__s32,
+        pub vid_hdr_offset: crate::__s32,
+        pub max_beb_per1024: crate::__s16,
+        pub disable_fm: crate::__s8,
+        pub padding: [crate::__s8; 9],
+    }
+
+    #[repr(packed)]
+    pub struct ubi_mkvol_req {
+        pub vol_id: crate::__s32,
+        pub alignment: crate::__s32,
+        pub bytes: crate::__s64,
+        pub vol_type: crate::__s8,
+        pub flags: crate::__u8,
+        pub name_len: crate::__s16,
+        pub padding2: [crate::__s8; 4],
+        pub name: [c_char; UBI_MAX_VOLUME_NAME + 1],
+    }
+
+    #[repr(packed)]
+    pub struct ubi_rsvol_req {
+        pub bytes: crate::__s64,
+        pub vol_id: crate::__s32,
+    }
+
+    #[repr(packed)]
+    pub struct __c_anonymous_ubi_rnvol_req_ents {
+        pub vol_id: crate::__s32,
+        pub name_len: crate::__s16,
+        pub padding2: [crate::__s8; 2],
+        pub name: [c_char; UBI_MAX_VOLUME_NAME + 1],
+    }
+
+    #[repr(packed)]
+    pub struct ubi_rnvol_req {
+        pub count: crate::__s32,
+        pub padding1: [crate::__s8; 12],
+        pub ents: [__c_anonymous_ubi_rnvol_req_ents; UBI_MAX_RNVOL],
+    }
+
+    #[repr(packed)]
+    pub struct ubi_leb_change_req {
+        pub lnum: crate::__s32,
+        pub bytes: crate::__s32,
+        pub dtype: crate::__s8,
+        pub padding: [crate::__s8; 7],
+    }
+
+    #[repr(packed)]
+    pub struct ubi_map_req {
+        pub lnum: crate::__s32,
+        pub dtype: crate::__s8,
+        pub padding: [crate::__s8; 3],
+    }
+
+    #[repr(packed)]
+    pub struct ubi_set_vol_prop_req {
+        pub property: crate::__u8,
+        pub padding: [crate::__u8; 7],
+        pub value: crate::__u64,
+    }
+
+    #[repr(packed)]
+    pub struct ubi_blkcreate_req {
+        pub padding: [crate::__s8; 128],
+    }
+}
@@ -174,26 +174,41 @@ cfg_if! {
 cfg_if! {
     if #[cfg(target_os = "android")] {
         use bionic_libc::kernel_uapi::linux;
+        pub use linux::android::binder::*;
+        pub use linux::ashmem::*;
+        pub use linux::mqueue::*;
+        pub use linux::sync_file::*;
         pub use linux::types::*;
         pub use sys::socket::*;
     } else if #[cfg(target_os = "linux")] {
+        pub use linux::blkzoned::*;
         pub use linux::can::bcm::*;
         pub use linux::can::error::*;
         pub use linux::can::j1939::*;
         pub use linux::can::netlink::*;
         pub use linux::can::raw::*;
+        pub use linux::cgroupstats::*;
+        pub use linux::dm_ioctl::*;
+        pub use linux::fuse::*;
         pub use linux::futex::*;
+        pub use linux::gpio::*;
         pub use linux::if_addr::*;
         pub use linux::if_link::*;
         pub use linux::if_packet::*;
+        pub use linux::kcmp::*;
         pub use linux::keyctl::*;
         pub use linux::membarrier::*;
         pub use linux::mount::*;
         pub use linux::netlink::*;
         pub use linux::pidfd::*;
+        pub use linux::random::*;
         pub use linux::sctp::*;
+        pub use linux::sync_file::*;
+        pub use linux::taskstats::*;
         pub use linux::tls::*;
         pub use linux::types::*;
+        pub use mtd::mtd_abi::*;
+        pub use mtd::ubi_user::*;
         #[cfg(target_env = "gnu")]
         pub use net::route::*;
     } else if #[cfg(target_vendor = "apple")] {
@@ -229,6 +244,15 @@ cfg_if! {
 cfg_if! {
     if #[cfg(any(target_env = "musl", target_env = "ohos"))] {
         pub use sys::socket::*;
+    } else if #[cfg(all(
+        target_family = "unix",
+        target_env = "gnu",
+        not(target_os = "vxworks")
+    ))] {
+        pub use argp_::*;
+        pub use error_::*;
+        pub use obstack_::*;
+        pub use printf::*;
     }
 }
 
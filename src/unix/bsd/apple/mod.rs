@@ -73,6 +73,8 @@ pub type memory_object_t = crate::mach_port_t;
 pub type memory_object_offset_t = c_ulonglong;
 pub type vm_inherit_t = c_uint;
 pub type vm_prot_t = c_int;
+pub type vm_behavior_t = c_int;
+pub type memory_object_name_t = crate::mach_port_t;
 
 pub type ledger_t = crate::mach_port_t;
 pub type ledger_array_t = *mut crate::ledger_t;
@@ -95,10 +97,21 @@ pub type processor_set_load_info_t = *mut processor_set_load_info;
 pub type processor_info_t = *mut integer_t;
 pub type processor_info_array_t = *mut integer_t;
 
+// mach/vm_region.h
+pub type vm_region_flavor_t = c_int;
+pub type vm_region_info_t = *mut c_int;
+pub type vm_region_info_64_t = *mut c_int;
+pub type vm_region_basic_info_64_t = *mut vm_region_basic_info_64;
+pub type vm_region_basic_info_data_64_t = vm_region_basic_info_64;
+
 pub type mach_task_basic_info_data_t = mach_task_basic_info;
 pub type mach_task_basic_info_t = *mut mach_task_basic_info;
 pub type task_thread_times_info_data_t = task_thread_times_info;
 pub type task_thread_times_info_t = *mut task_thread_times_info;
+pub type task_basic_info_64_data_t = task_basic_info_64;
+pub type task_basic_info_64_t = *mut task_basic_info_64;
+pub type task_vm_info_data_t = task_vm_info;
+pub type task_vm_info_t = *mut task_vm_info;
 
 pub type thread_info_t = *mut integer_t;
 pub type thread_basic_info_t = *mut thread_basic_info;
@@ -174,6 +187,7 @@ pub type copyfile_callback_t = Option<
 
 pub type attrgroup_t = u32;
 pub type vol_capabilities_set_t = [u32; 4];
+pub type fsobj_id_t = fsobj_id;
 
 extern_ty! {
     pub type timezone;
@@ -902,6 +916,35 @@ s! {
         pub pvi_rdir: vnode_info_path,
     }
 
+    pub struct proc_regioninfo {
+        pub pri_protection: u32,
+        pub pri_max_protection: u32,
+        pub pri_inheritance: u32,
+        pub pri_flags: u32,
+        pub pri_offset: u64,
+        pub pri_behavior: u32,
+        pub pri_user_wired_count: u32,
+        pub pri_user_tag: u32,
+        pub pri_pages_resident: u32,
+        pub pri_pages_shared_now_private: u32,
+        pub pri_pages_swapped_out: u32,
+        pub pri_pages_dirtied: u32,
+        pub pri_ref_count: u32,
+        pub pri_shadow_depth: u32,
+        pub pri_share_mode: u32,
+        pub pri_private_pages_resident: u32,
+        pub pri_shared_pages_resident: u32,
+        pub pri_obj_id: u32,
+        pub pri_depth: u32,
+        pub pri_address: u64,
+        pub pri_size: u64,
+    }
+
+    pub struct proc_regionwithpathinfo {
+        pub prp_prinfo: proc_regioninfo,
+        pub prp_vip: vnode_info_path,
+    }
+
     pub struct vm_statistics {
         pub free_count: natural_t,
         pub active_count: natural_t,
@@ -1071,6 +1114,11 @@ s! {
         pub attr_length: u32,
     }
 
+    pub struct fsobj_id {
+        pub fid_objno: u32,
+        pub fid_generation: u32,
+    }
+
     pub struct vol_capabilities_attr_t {
         pub capabilities: vol_capabilities_set_t,
         pub valid: vol_capabilities_set_t,
@@ -1513,6 +1561,80 @@ s! {
         pub suspend_count: integer_t,
     }
 
+    #[repr(packed(4))]
+    pub struct task_basic_info_64 {
+        pub suspend_count: integer_t,
+        pub virtual_size: mach_vm_size_t,
+        pub resident_size: mach_vm_size_t,
+        pub user_time: time_value_t,
+        pub system_time: time_value_t,
+        pub policy: crate::policy_t,
+    }
+
+    #[repr(packed(4))]
+    pub struct task_vm_info {
+        pub virtual_size: mach_vm_size_t,
+        pub region_count: integer_t,
+        pub page_size: integer_t,
+        pub resident_size: mach_vm_size_t,
+        pub resident_size_peak: mach_vm_size_t,
+        pub device: mach_vm_size_t,
+        pub device_peak: mach_vm_size_t,
+        pub internal: mach_vm_size_t,
+        pub internal_peak: mach_vm_size_t,
+        pub external: mach_vm_size_t,
+        pub external_peak: mach_vm_size_t,
+        pub reusable: mach_vm_size_t,
+        pub reusable_peak: mach_vm_size_t,
+        pub purgeable_volatile_pmap: mach_vm_size_t,
+        pub purgeable_volatile_resident: mach_vm_size_t,
+        pub purgeable_volatile_virtual: mach_vm_size_t,
+        pub compressed: mach_vm_size_t,
+        pub compressed_peak: mach_vm_size_t,
+        pub compressed_lifetime: mach_vm_size_t,
+        pub phys_footprint: mach_vm_size_t,
+        pub min_address: mach_vm_address_t,
+        pub max_address: mach_vm_address_t,
+        pub ledger_phys_footprint_peak: i64,
+        pub ledger_purgeable_nonvolatile: i64,
+        pub ledger_purgeable_novolatile_compressed: i64,
+        pub ledger_purgeable_volatile: i64,
+        pub ledger_purgeable_volatile_compressed: i64,
+        pub ledger_tag_network_nonvolatile: i64,
+        pub ledger_tag_network_nonvolatile_compressed: i64,
+        pub ledger_tag_network_volatile: i64,
+        pub ledger_tag_network_volatile_compressed: i64,
+        pub ledger_tag_media_footprint: i64,
+        pub ledger_tag_media_footprint_compressed: i64,
+        pub ledger_tag_media_nofootprint: i64,
+        pub ledger_tag_media_nofootprint_compressed: i64,
+        pub ledger_tag_graphics_footprint: i64,
+        pub ledger_tag_graphics_footprint_compressed: i64,
+        pub ledger_tag_graphics_nofootprint: i64,
+        pub ledger_tag_graphics_nofootprint_compressed: i64,
+        pub ledger_tag_neural_footprint: i64,
+        pub ledger_tag_neural_footprint_compressed: i64,
+        pub ledger_tag_neural_nofootprint: i64,
+        pub ledger_tag_neural_nofootprint_compressed: i64,
+        pub limit_bytes_remaining: u64,
+        pub decompressions: integer_t,
+        pub ledger_swapins: i64,
+        pub ledger_tag_neural_nofootprint_total: i64,
+        pub ledger_tag_neural_nofootprint_peak: i64,
+    }
+
+    #[repr(packed(4))]
+    pub struct vm_region_basic_info_64 {
+        pub protection: vm_prot_t,
+        pub max_protection: vm_prot_t,
+        pub inheritance: vm_inherit_t,
+        pub shared: crate::boolean_t,
+        pub reserved: crate::boolean_t,
+        pub offset: memory_object_offset_t,
+        pub behavior: vm_behavior_t,
+        pub user_wired_count: c_ushort,
+    }
+
     #[repr(packed(4))]
     pub struct log2phys {
         pub l2p_flags: c_uint,
@@ -1973,6 +2095,7 @@ pub const S_IFDIR: mode_t = 0o4_0000;
 pub const S_IFREG: mode_t = 0o10_0000;
 pub const S_IFLNK: mode_t = 0o12_0000;
 pub const S_IFSOCK: mode_t = 0o14_0000;
+pub const S_IFWHT: mode_t = 0o16_0000;
 pub const S_IFMT: mode_t = 0o17_0000;
 pub const S_IEXEC: mode_t = 0o0100;
 pub const S_IWRITE: mode_t = 0o0200;
@@ -1989,6 +2112,9 @@ pub const S_IRWXO: mode_t = 0o0007;
 pub const S_IXOTH: mode_t = 0o0001;
 pub const S_IWOTH: mode_t = 0o0002;
 pub const S_IROTH: mode_t = 0o0004;
+pub const ACCESSPERMS: mode_t = 0o0777;
+pub const ALLPERMS: mode_t = 0o7777;
+pub const DEFFILEMODE: mode_t = 0o0666;
 pub const F_OK: c_int = 0;
 pub const R_OK: c_int = 4;
 pub const W_OK: c_int = 2;
@@ -3173,6 +3299,8 @@ pub const NI_DGRAM: c_int = 0x00000010;
 
 pub const Q_GETQUOTA: c_int = 0x300;
 pub const Q_SETQUOTA: c_int = 0x400;
+pub const Q_SETUSE: c_int = 0x500;
+pub const Q_QUOTASTAT: c_int = 0x700;
 
 pub const RENAME_SWAP: c_uint = 0x00000002;
 pub const RENAME_EXCL: c_uint = 0x00000004;
@@ -3403,11 +3531,32 @@ pub const VM_LOADAVG: c_int = 2;
 pub const VM_MACHFACTOR: c_int = 4;
 pub const VM_SWAPUSAGE: c_int = 5;
 pub const VM_MAXID: c_int = 6;
+
 pub const VM_PROT_NONE: crate::vm_prot_t = 0x00;
 pub const VM_PROT_READ: crate::vm_prot_t = 0x01;
 pub const VM_PROT_WRITE: crate::vm_prot_t = 0x02;
 pub const VM_PROT_EXECUTE: crate::vm_prot_t = 0x04;
+pub const VM_PROT_DEFAULT: crate::vm_prot_t = VM_PROT_READ | VM_PROT_WRITE;
+pub const VM_PROT_ALL: crate::vm_prot_t = VM_PROT_READ | VM_PROT_WRITE | VM_PROT_EXECUTE;
 pub const MEMORY_OBJECT_NULL: crate::memory_object_t = 0;
+
+// mach/vm_statistics.h
+pub const VM_FLAGS_FIXED: c_int = 0x0000;
+pub const VM_FLAGS_ANYWHERE: c_int = 0x0001;
+pub const VM_FLAGS_PURGABLE: c_int = 0x0002;
+pub const VM_FLAGS_RANDOM_ADDR: c_int = 0x0008;
+pub const VM_FLAGS_NO_CACHE: c_int = 0x0010;
+pub const VM_FLAGS_OVERWRITE: c_int = 0x4000;
+
+// mach/vm_inherit.h
+pub const VM_INHERIT_SHARE: vm_inherit_t = 0;
+pub const VM_INHERIT_COPY: vm_inherit_t = 1;
+pub const VM_INHERIT_NONE: vm_inherit_t = 2;
+pub const VM_INHERIT_DONATE_COPY: vm_inherit_t = 3;
+pub const VM_INHERIT_DEFAULT: vm_inherit_t = VM_INHERIT_COPY;
+
+// mach/vm_region.h
+pub const VM_REGION_BASIC_INFO_64: vm_region_flavor_t = 9;
 pub const HW_MACHINE: c_int = 1;
 pub const HW_MODEL: c_int = 2;
 pub const HW_NCPU: c_int = 3;
@@ -3598,10 +3747,24 @@ pub const PROC_PIDTASKALLINFO: c_int = 2;
 pub const PROC_PIDTBSDINFO: c_int = 3;
 pub const PROC_PIDTASKINFO: c_int = 4;
 pub const PROC_PIDTHREADINFO: c_int = 5;
+pub const PROC_PIDREGIONINFO: c_int = 7;
+pub const PROC_PIDREGIONPATHINFO: c_int = 8;
 pub const PROC_PIDVNODEPATHINFO: c_int = 9;
 pub const PROC_PIDT_SHORTBSDINFO: c_int = 13;
 pub const PROC_PIDPATHINFO_MAXSIZE: c_int = 4096;
 
+pub const PROC_REGION_SUBMAP: c_int = 1;
+pub const PROC_REGION_SHARED: c_int = 2;
+
+pub const SM_COW: c_int = 1;
+pub const SM_PRIVATE: c_int = 2;
+pub const SM_EMPTY: c_int = 3;
+pub const SM_SHARED: c_int = 4;
+pub const SM_TRUESHARED: c_int = 5;
+pub const SM_PRIVATE_ALIASED: c_int = 6;
+pub const SM_SHARED_ALIASED: c_int = 7;
+pub const SM_LARGE_PAGE: c_int = 8;
+
 pub const PROC_PIDLISTFDS: c_int = 1;
 pub const PROC_PIDLISTFD_SIZE: c_int = size_of::<proc_fdinfo>() as c_int;
 pub const PROX_FDTYPE_ATALK: c_int = 0;
@@ -3733,10 +3896,17 @@ pub const UF_APPEND: c_uint = 0x00000004;
 pub const UF_OPAQUE: c_uint = 0x00000008;
 pub const UF_COMPRESSED: c_uint = 0x00000020;
 pub const UF_TRACKED: c_uint = 0x00000040;
+pub const UF_DATAVAULT: c_uint = 0x00000080;
+pub const SF_SUPPORTED: c_uint = 0x009f0000;
 pub const SF_SETTABLE: c_uint = 0x3fff0000;
+pub const SF_SYNTHETIC: c_uint = 0xc0000000;
 pub const SF_ARCHIVED: c_uint = 0x00010000;
 pub const SF_IMMUTABLE: c_uint = 0x00020000;
 pub const SF_APPEND: c_uint = 0x00040000;
+pub const SF_RESTRICTED: c_uint = 0x00080000;
+pub const SF_NOUNLINK: c_uint = 0x00100000;
+pub const SF_FIRMLINK: c_uint = 0x00800000;
+pub const SF_DATALESS: c_uint = 0x40000000;
 pub const UF_HIDDEN: c_uint = 0x00008000;
 
 //<sys/timex.h>
@@ -3863,6 +4033,12 @@ pub const VM_PAGE_QUERY_PAGE_CS_NX: i32 = 0x400;
 
 // mach/task_info.h
 pub const TASK_THREAD_TIMES_INFO: u32 = 3;
+#[cfg(target_arch = "aarch64")]
+pub const TASK_BASIC_INFO_64: u32 = 18;
+#[cfg(not(target_arch = "aarch64"))]
+pub const TASK_BASIC_INFO_64: u32 = 5;
+pub const TASK_VM_INFO: u32 = 22;
+pub const TASK_VM_INFO_PURGEABLE: u32 = 23;
 
 /// This symbol is prone to change across releases upstream.
 /// See the [usage guidelines](crate#usage-guidelines) for details.
@@ -3878,6 +4054,10 @@ pub const RUSAGE_INFO_V2: c_int = 2;
 pub const RUSAGE_INFO_V3: c_int = 3;
 pub const RUSAGE_INFO_V4: c_int = 4;
 
+// sys/clonefile.h
+pub const CLONE_NOFOLLOW: u32 = 0x0001;
+pub const CLONE_NOOWNERCOPY: u32 = 0x0002;
+
 // copyfile.h
 pub const COPYFILE_ACL: crate::copyfile_flags_t = 1 << 0;
 pub const COPYFILE_STAT: crate::copyfile_flags_t = 1 << 1;
@@ -3969,8 +4149,10 @@ pub const ATTR_CMN_FILEID: attrgroup_t = 0x02000000;
 pub const ATTR_CMN_PARENTID: attrgroup_t = 0x04000000;
 pub const ATTR_CMN_FULLPATH: attrgroup_t = 0x08000000;
 pub const ATTR_CMN_ADDEDTIME: attrgroup_t = 0x10000000;
+pub const ATTR_CMN_ERROR: attrgroup_t = 0x20000000;
 pub const ATTR_CMN_DATA_PROTECT_FLAGS: attrgroup_t = 0x40000000;
 pub const ATTR_CMN_RETURNED_ATTRS: attrgroup_t = 0x80000000;
+pub const ATTR_BULK_REQUIRED: attrgroup_t = ATTR_CMN_NAME | ATTR_CMN_RETURNED_ATTRS;
 pub const ATTR_VOL_FSTYPE: attrgroup_t = 0x00000001;
 pub const ATTR_VOL_SIGNATURE: attrgroup_t = 0x00000002;
 pub const ATTR_VOL_SIZE: attrgroup_t = 0x00000004;
@@ -4169,10 +4351,23 @@ pub const TASK_THREAD_TIMES_INFO_COUNT: u32 =
 pub const MACH_TASK_BASIC_INFO_COUNT: u32 =
     (size_of::<mach_task_basic_info_data_t>() / size_of::<natural_t>()) as u32;
 
+pub const TASK_BASIC_INFO_64_COUNT: u32 =
+    (size_of::<task_basic_info_64_data_t>() / size_of::<natural_t>()) as u32;
+
+/// This symbol is prone to change across releases upstream.
+/// See the [usage guidelines](crate#usage-guidelines) for details.
+pub const TASK_VM_INFO_COUNT: u32 =
+    (size_of::<task_vm_info_data_t>() / size_of::<natural_t>()) as u32;
+
 /// This symbol is prone to change across releases upstream.
 /// See the [usage guidelines](crate#usage-guidelines) for details.
 pub const HOST_VM_INFO64_COUNT: mach_msg_type_number_t =
     (size_of::<vm_statistics64_data_t>() / size_of::<integer_t>()) as mach_msg_type_number_t;
+/// This symbol is prone to change across releases upstream.
+/// See the [usage guidelines](crate#usage-guidelines) for details.
+pub const VM_REGION_BASIC_INFO_COUNT_64: mach_msg_type_number_t =
+    (size_of::<vm_region_basic_info_data_64_t>() / size_of::<integer_t>())
+        as mach_msg_type_number_t;
 
 // bsd/net/if_mib.h
 /// Non-interface-specific
@@ -4267,6 +4462,59 @@ safe_f! {
     pub const fn minor(dev: u32) -> i32 {
         (dev & 0xffffff) as i32
     }
+
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        fac | pri
+    }
+
+    // <libkern/OSByteOrder.h>
+    pub const fn OSSwapHostToBigInt16(x: u16) -> u16 {
+        u16::to_be(x)
+    }
+
+    pub const fn OSSwapHostToLittleInt16(x: u16) -> u16 {
+        u16::to_le(x)
+    }
+
+    pub const fn OSSwapBigToHostInt16(x: u16) -> u16 {
+        u16::from_be(x)
+    }
+
+    pub const fn OSSwapLittleToHostInt16(x: u16) -> u16 {
+        u16::from_le(x)
+    }
+
+    pub const fn OSSwapHostToBigInt32(x: u32) -> u32 {
+        u32::to_be(x)
+    }
+
+    pub const fn OSSwapHostToLittleInt32(x: u32) -> u32 {
+        u32::to_le(x)
+    }
+
+    pub const fn OSSwapBigToHostInt32(x: u32) -> u32 {
+        u32::from_be(x)
+    }
+
+    pub const fn OSSwapLittleToHostInt32(x: u32) -> u32 {
+        u32::from_le(x)
+    }
+
+    pub const fn OSSwapHostToBigInt64(x: u64) -> u64 {
+        u64::to_be(x)
+    }
+
+    pub const fn OSSwapHostToLittleInt64(x: u64) -> u64 {
+        u64::to_le(x)
+    }
+
+    pub const fn OSSwapBigToHostInt64(x: u64) -> u64 {
+        u64::from_be(x)
+    }
+
+    pub const fn OSSwapLittleToHostInt64(x: u64) -> u64 {
+        u64::from_le(x)
+    }
 }
 
 extern "C" {
@@ -4466,6 +4714,9 @@ extern "C" {
     pub fn os_unfair_lock_assert_owner(lock: os_unfair_lock_t);
     pub fn os_unfair_lock_assert_not_owner(lock: os_unfair_lock_t);
 
+    pub fn os_retain(object: *mut c_void) -> *mut c_void;
+    pub fn os_release(object: *mut c_void);
+
     pub fn os_log_create(subsystem: *const c_char, category: *const c_char) -> crate::os_log_t;
     pub fn os_log_type_enabled(oslog: crate::os_log_t, tpe: crate::os_log_type_t) -> bool;
     pub fn os_signpost_id_make_with_pointer(
@@ -4573,6 +4824,8 @@ extern "C" {
         winp: *mut crate::winsize,
     ) -> crate::pid_t;
     pub fn login_tty(fd: c_int) -> c_int;
+    // Available starting with macOS 13.3.
+    pub fn ptsname_r(fd: c_int, buf: *mut c_char, buflen: size_t) -> c_int;
     pub fn duplocale(base: crate::locale_t) -> crate::locale_t;
     pub fn freelocale(loc: crate::locale_t) -> c_int;
     pub fn localeconv_l(loc: crate::locale_t) -> *mut lconv;
@@ -4739,6 +4992,14 @@ extern "C" {
         fd: c_int,
         newfd: c_int,
     ) -> c_int;
+    pub fn posix_spawn_file_actions_addchdir_np(
+        actions: *mut posix_spawn_file_actions_t,
+        path: *const c_char,
+    ) -> c_int;
+    pub fn posix_spawn_file_actions_addfchdir_np(
+        actions: *mut posix_spawn_file_actions_t,
+        fd: c_int,
+    ) -> c_int;
     pub fn uname(buf: *mut crate::utsname) -> c_int;
 
     pub fn connectx(
@@ -4806,6 +5067,8 @@ extern "C" {
     pub fn memset_pattern4(b: *mut c_void, pattern4: *const c_void, len: size_t);
     pub fn memset_pattern8(b: *mut c_void, pattern8: *const c_void, len: size_t);
     pub fn memset_pattern16(b: *mut c_void, pattern16: *const c_void, len: size_t);
+    // Available starting with macOS 15.4.
+    pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
 
     // Inherited from BSD but available from Big Sur only
     pub fn strtonum(
@@ -4911,6 +5174,47 @@ extern "C" {
         size: vm_size_t,
     ) -> crate::kern_return_t;
 
+    pub fn mach_vm_allocate(
+        target: vm_map_t,
+        address: *mut mach_vm_address_t,
+        size: mach_vm_size_t,
+        flags: c_int,
+    ) -> crate::kern_return_t;
+    pub fn mach_vm_deallocate(
+        target: vm_map_t,
+        address: mach_vm_address_t,
+        size: mach_vm_size_t,
+    ) -> crate::kern_return_t;
+    pub fn mach_vm_protect(
+        target_task: vm_map_t,
+        address: mach_vm_address_t,
+        size: mach_vm_size_t,
+        set_maximum: crate::boolean_t,
+        new_protection: vm_prot_t,
+    ) -> crate::kern_return_t;
+    pub fn mach_vm_remap(
+        target_task: vm_map_t,
+        target_address: *mut mach_vm_address_t,
+        size: mach_vm_size_t,
+        mask: mach_vm_offset_t,
+        flags: c_int,
+        src_task: vm_map_t,
+        src_address: mach_vm_address_t,
+        copy: crate::boolean_t,
+        cur_protection: *mut vm_prot_t,
+        max_protection: *mut vm_prot_t,
+        inheritance: vm_inherit_t,
+    ) -> crate::kern_return_t;
+    pub fn mach_vm_region(
+        target_task: vm_map_t,
+        address: *mut mach_vm_address_t,
+        size: *mut mach_vm_size_t,
+        flavor: vm_region_flavor_t,
+        info: vm_region_info_t,
+        infoCnt: *mut mach_msg_type_number_t,
+        object_name: *mut memory_object_name_t,
+    ) -> crate::kern_return_t;
+
     pub fn host_statistics64(
         host_priv: host_t,
         flavor: host_flavor_t,
@@ -5020,6 +5324,13 @@ extern "C" {
         options: u64,
     ) -> c_int;
 
+    pub fn fsgetpath(
+        buf: *mut c_char,
+        bufsize: size_t,
+        fsid: *mut crate::fsid_t,
+        objid: u64,
+    ) -> ssize_t;
+
     pub fn malloc_size(ptr: *const c_void) -> size_t;
     pub fn malloc_good_size(size: size_t) -> size_t;
 
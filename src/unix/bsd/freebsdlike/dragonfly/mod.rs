@@ -527,6 +527,7 @@ pub const RLIM_NLIMITS: crate::rlim_t = 12;
 
 pub const Q_GETQUOTA: c_int = 0x300;
 pub const Q_SETQUOTA: c_int = 0x400;
+pub const Q_SETUSE: c_int = 0x500;
 
 pub const CTL_UNSPEC: c_int = 0;
 pub const CTL_KERN: c_int = 1;
@@ -613,6 +614,17 @@ pub const HW_MACHINE_ARCH: c_int = 11;
 pub const HW_MACHINE_PLATFORM: c_int = 12;
 pub const HW_SENSORS: c_int = 13;
 pub const HW_MAXID: c_int = 14;
+pub const VM_METER: c_int = 1;
+pub const VM_LOADAVG: c_int = 2;
+pub const VM_V_FREE_MIN: c_int = 3;
+pub const VM_V_FREE_TARGET: c_int = 4;
+pub const VM_V_FREE_RESERVED: c_int = 5;
+pub const VM_V_INACTIVE_TARGET: c_int = 6;
+pub const VM_V_CACHE_MIN: c_int = 7;
+pub const VM_V_CACHE_MAX: c_int = 8;
+pub const VM_V_PAGEOUT_FREE_MIN: c_int = 9;
+pub const VM_PAGEOUT_ALGORITHM: c_int = 10;
+pub const VM_SWAPPING_ENABLED: c_int = 11;
 pub const USER_CS_PATH: c_int = 1;
 
 /// This symbol is prone to change across releases upstream.
@@ -1331,6 +1343,7 @@ extern "C" {
 
     pub fn freelocale(loc: crate::locale_t);
 
+    pub fn lwp_gettid() -> lwpid_t;
     pub fn lwp_rtprio(
         function: c_int,
         pid: crate::pid_t,
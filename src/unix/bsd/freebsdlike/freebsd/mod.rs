@@ -1597,6 +1597,16 @@ s! {
 }
 
 s_no_extra_traits! {
+    /// Argument for `semctl` commands that take one.
+    ///
+    /// It must be passed to `semctl` by value as the fourth argument, not as a
+    /// pointer. [`semctl_semun`] does this with the correct type.
+    pub union semun {
+        pub val: c_int,
+        pub buf: *mut semid_ds,
+        pub array: *mut c_ushort,
+    }
+
     pub struct __aiocb_private {
         status: c_long,
         error: c_long,
@@ -1753,6 +1763,18 @@ s_no_extra_traits! {
 
 cfg_if! {
     if #[cfg(feature = "extra_traits")] {
+        impl PartialEq for semun {
+            fn eq(&self, other: &semun) -> bool {
+                unsafe { self.val == other.val }
+            }
+        }
+        impl Eq for semun {}
+        impl hash::Hash for semun {
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                unsafe { self.val.hash(state) };
+            }
+        }
+
         impl PartialEq for __c_anonymous_cr_pid {
             fn eq(&self, other: &__c_anonymous_cr_pid) -> bool {
                 unsafe { self.cr_pid == other.cr_pid }
@@ -2072,6 +2094,8 @@ pub const XU_NGROUPS: c_int = 16;
 
 pub const Q_GETQUOTA: c_int = 0x700;
 pub const Q_SETQUOTA: c_int = 0x800;
+pub const Q_SETUSE: c_int = 0x900;
+pub const Q_GETQUOTASIZE: c_int = 0xa00;
 
 pub const MAP_GUARD: c_int = 0x00002000;
 pub const MAP_EXCL: c_int = 0x00004000;
@@ -2448,6 +2472,15 @@ pub const TIOCM_DCD: c_int = 0x40;
 pub const H4DISC: c_int = 0x7;
 
 pub const VM_TOTAL: c_int = 1;
+pub const VM_METER: c_int = VM_TOTAL;
+pub const VM_LOADAVG: c_int = 2;
+pub const VM_V_FREE_MIN: c_int = 3;
+pub const VM_V_FREE_TARGET: c_int = 4;
+pub const VM_V_FREE_RESERVED: c_int = 5;
+pub const VM_V_INACTIVE_TARGET: c_int = 6;
+pub const VM_V_PAGEOUT_FREE_MIN: c_int = 9;
+pub const VM_SWAPPING_ENABLED: c_int = 11;
+pub const VM_OVERCOMMIT: c_int = 12;
 
 cfg_if! {
     if #[cfg(target_pointer_width = "64")] {
@@ -3234,6 +3267,17 @@ pub const SHM_STAT: c_int = 13;
 pub const SHM_INFO: c_int = 14;
 pub const SHM_ANON: *mut c_char = 1 as *mut c_char;
 
+// sys/sem.h
+pub const SEM_UNDO: c_int = 0o10000;
+
+pub const GETNCNT: c_int = 3;
+pub const GETPID: c_int = 4;
+pub const GETVAL: c_int = 5;
+pub const GETALL: c_int = 6;
+pub const GETZCNT: c_int = 7;
+pub const SETVAL: c_int = 8;
+pub const SETALL: c_int = 9;
+
 pub const MSG_NOTIFICATION: c_int = 0x00002000;
 pub const MSG_NBIO: c_int = 0x00004000;
 pub const MSG_COMPAT: c_int = 0x00008000;
@@ -4152,6 +4196,8 @@ pub const RTAX_MAX: c_int = 8;
 pub const SIGTHR: c_int = 32;
 pub const SIGLWP: c_int = SIGTHR;
 pub const SIGLIBRT: c_int = 33;
+pub const SIGRTMIN: c_int = 65;
+pub const SIGRTMAX: c_int = 126;
 
 // netinet/sctp.h
 pub const SCTP_FUTURE_ASSOC: c_int = 0;
@@ -4367,6 +4413,11 @@ const fn _ALIGN(p: usize) -> usize {
 }
 
 f! {
+    /// Call `semctl` with a [`semun`] argument, passed by value.
+    pub fn semctl_semun(semid: c_int, semnum: c_int, cmd: c_int, arg: semun) -> c_int {
+        crate::semctl(semid, semnum, cmd, arg)
+    }
+
     pub fn CMSG_DATA(cmsg: *const cmsghdr) -> *mut c_uchar {
         (cmsg as *mut c_uchar).add(_ALIGN(size_of::<cmsghdr>()))
     }
@@ -4670,6 +4721,7 @@ extern "C" {
         options: c_int,
     ) -> c_int;
     pub fn ptsname_r(fd: c_int, buf: *mut c_char, buflen: size_t) -> c_int;
+    pub fn futimesat(fd: c_int, path: *const c_char, times: *const crate::timeval) -> c_int;
 
     pub fn ftok(pathname: *const c_char, proj_id: c_int) -> crate::key_t;
     pub fn shmget(key: crate::key_t, size: size_t, shmflg: c_int) -> c_int;
@@ -4881,7 +4933,6 @@ extern "C" {
     pub fn getpagesizes(pagesize: *mut size_t, nelem: c_int) -> c_int;
 
     pub fn clock_getcpuclockid2(arg1: crate::id_t, arg2: c_int, arg3: *mut clockid_t) -> c_int;
-    pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
 
     pub fn shm_create_largepage(
         path: *const c_char,
@@ -4974,6 +5025,14 @@ extern "C" {
     ) -> c_int;
     pub fn closefrom(lowfd: c_int);
     pub fn close_range(lowfd: c_uint, highfd: c_uint, flags: c_int) -> c_int;
+    pub fn posix_spawn_file_actions_addchdir_np(
+        actions: *mut crate::posix_spawn_file_actions_t,
+        path: *const c_char,
+    ) -> c_int;
+    pub fn posix_spawn_file_actions_addfchdir_np(
+        actions: *mut crate::posix_spawn_file_actions_t,
+        fd: c_int,
+    ) -> c_int;
 
     pub fn execvpe(
         file: *const c_char,
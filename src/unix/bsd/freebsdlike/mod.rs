@@ -525,6 +525,7 @@ pub const S_IFDIR: mode_t = 0o4_0000;
 pub const S_IFREG: mode_t = 0o10_0000;
 pub const S_IFLNK: mode_t = 0o12_0000;
 pub const S_IFSOCK: mode_t = 0o14_0000;
+pub const S_IFWHT: mode_t = 0o16_0000;
 pub const S_IFMT: mode_t = 0o17_0000;
 pub const S_IEXEC: mode_t = 0o0100;
 pub const S_IWRITE: mode_t = 0o0200;
@@ -541,6 +542,9 @@ pub const S_IRWXO: mode_t = 0o0007;
 pub const S_IXOTH: mode_t = 0o0001;
 pub const S_IWOTH: mode_t = 0o0002;
 pub const S_IROTH: mode_t = 0o0004;
+pub const ACCESSPERMS: mode_t = 0o0777;
+pub const ALLPERMS: mode_t = 0o7777;
+pub const DEFFILEMODE: mode_t = 0o0666;
 pub const F_OK: c_int = 0;
 pub const R_OK: c_int = 4;
 pub const W_OK: c_int = 2;
@@ -1448,6 +1452,10 @@ safe_f! {
     pub const fn WIFSTOPPED(status: c_int) -> bool {
         (status & 0o177) == 0o177
     }
+
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        fac | pri
+    }
 }
 
 extern "C" {
@@ -1455,6 +1463,8 @@ extern "C" {
     pub fn sem_init(sem: *mut sem_t, pshared: c_int, value: c_uint) -> c_int;
 
     pub fn daemon(nochdir: c_int, noclose: c_int) -> c_int;
+    pub fn quick_exit(status: c_int) -> !;
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
     pub fn gettimeofday(tp: *mut crate::timeval, tz: *mut crate::timezone) -> c_int;
     pub fn accept4(
         s: c_int,
@@ -1543,6 +1553,7 @@ extern "C" {
     pub fn lchflags(path: *const c_char, flags: c_ulong) -> c_int;
     pub fn lutimes(file: *const c_char, times: *const crate::timeval) -> c_int;
     pub fn memrchr(cx: *const c_void, c: c_int, n: size_t) -> *mut c_void;
+    pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
     pub fn mkfifoat(dirfd: c_int, pathname: *const c_char, mode: mode_t) -> c_int;
     #[cfg_attr(
         all(target_os = "freebsd", any(freebsd11, freebsd10)),
@@ -1574,6 +1585,11 @@ extern "C" {
         stackaddr: *mut *mut c_void,
         stacksize: *mut size_t,
     ) -> c_int;
+    pub fn pthread_attr_setstack(
+        attr: *mut crate::pthread_attr_t,
+        stackaddr: *mut c_void,
+        stacksize: size_t,
+    ) -> c_int;
     pub fn pthread_condattr_getclock(
         attr: *const pthread_condattr_t,
         clock_id: *mut clockid_t,
@@ -1641,6 +1657,7 @@ extern "C" {
     pub fn utrace(addr: *const c_void, len: size_t) -> c_int;
     pub fn pututxline(ut: *const utmpx) -> *mut utmpx;
     pub fn pwritev(fd: c_int, iov: *const crate::iovec, iovcnt: c_int, offset: off_t) -> ssize_t;
+    pub fn quotactl(path: *const c_char, cmd: c_int, id: c_int, addr: *mut c_void) -> c_int;
     pub fn querylocale(mask: c_int, loc: crate::locale_t) -> *const c_char;
     pub fn rtprio(function: c_int, pid: crate::pid_t, rtp: *mut rtprio) -> c_int;
     pub fn sched_rr_get_interval(pid: crate::pid_t, t: *mut crate::timespec) -> c_int;
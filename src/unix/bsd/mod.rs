@@ -495,6 +495,48 @@ f! {
     pub fn FD_ZERO(set: *mut fd_set) -> () {
         (*set).fds_bits.fill(0);
     }
+
+    /// Stores `*a + *b` in `*result`, like the `<sys/time.h>` `timespecadd` macro.
+    #[cfg(not(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "tvos",
+        target_os = "watchos",
+        target_os = "visionos"
+    )))]
+    pub fn timespecadd(
+        a: *const crate::timespec,
+        b: *const crate::timespec,
+        result: *mut crate::timespec,
+    ) -> () {
+        (*result).tv_sec = (*a).tv_sec + (*b).tv_sec;
+        (*result).tv_nsec = (*a).tv_nsec + (*b).tv_nsec;
+        if (*result).tv_nsec >= 1_000_000_000 {
+            (*result).tv_sec += 1;
+            (*result).tv_nsec -= 1_000_000_000;
+        }
+    }
+
+    /// Stores `*a - *b` in `*result`, like the `<sys/time.h>` `timespecsub` macro.
+    #[cfg(not(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "tvos",
+        target_os = "watchos",
+        target_os = "visionos"
+    )))]
+    pub fn timespecsub(
+        a: *const crate::timespec,
+        b: *const crate::timespec,
+        result: *mut crate::timespec,
+    ) -> () {
+        (*result).tv_sec = (*a).tv_sec - (*b).tv_sec;
+        (*result).tv_nsec = (*a).tv_nsec - (*b).tv_nsec;
+        if (*result).tv_nsec < 0 {
+            (*result).tv_sec -= 1;
+            (*result).tv_nsec += 1_000_000_000;
+        }
+    }
 }
 
 safe_f! {
@@ -751,6 +793,8 @@ extern "C" {
         value: *mut c_void,
     ) -> c_int;
     pub fn acct(filename: *const c_char) -> c_int;
+    #[cfg_attr(target_os = "netbsd", link_name = "__wait350")]
+    pub fn wait3(status: *mut c_int, options: c_int, rusage: *mut crate::rusage) -> crate::pid_t;
     #[cfg_attr(
         all(target_os = "macos", target_arch = "x86"),
         link_name = "wait4$UNIX2003"
@@ -815,6 +859,15 @@ extern "C" {
     pub fn srand48(seed: c_long);
     pub fn seed48(xseed: *mut c_ushort) -> *mut c_ushort;
     pub fn lcong48(p: *mut c_ushort);
+
+    pub fn random() -> c_long;
+    pub fn srandom(seed: c_uint);
+    pub fn initstate(seed: c_uint, state: *mut c_char, size: size_t) -> *mut c_char;
+    #[cfg(any(target_os = "netbsd", target_os = "openbsd"))]
+    pub fn setstate(state: *mut c_char) -> *mut c_char;
+    #[cfg(not(any(target_os = "netbsd", target_os = "openbsd")))]
+    pub fn setstate(state: *const c_char) -> *mut c_char;
+
     pub fn getopt_long(
         argc: c_int,
         argv: *const *mut c_char,
@@ -841,6 +894,8 @@ extern "C" {
     pub fn devname(dev: crate::dev_t, mode_t: crate::mode_t) -> *mut c_char;
 
     pub fn issetugid() -> c_int;
+
+    pub fn strsep(string: *mut *mut c_char, delim: *const c_char) -> *mut c_char;
 }
 
 cfg_if! {
@@ -851,6 +906,19 @@ cfg_if! {
     }
 }
 
+// monetary.h, not provided by OpenBSD
+#[cfg(not(target_os = "openbsd"))]
+extern "C" {
+    pub fn strfmon(s: *mut c_char, max: size_t, format: *const c_char, ...) -> ssize_t;
+    pub fn strfmon_l(
+        s: *mut c_char,
+        max: size_t,
+        locale: crate::locale_t,
+        format: *const c_char,
+        ...
+    ) -> ssize_t;
+}
+
 cfg_if! {
     if #[cfg(any(
         target_os = "macos",
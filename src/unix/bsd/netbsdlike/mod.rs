@@ -206,6 +206,9 @@ pub const S_IRWXO: mode_t = 0o0007;
 pub const S_IXOTH: mode_t = 0o0001;
 pub const S_IWOTH: mode_t = 0o0002;
 pub const S_IROTH: mode_t = 0o0004;
+pub const ACCESSPERMS: mode_t = 0o0777;
+pub const ALLPERMS: mode_t = 0o7777;
+pub const DEFFILEMODE: mode_t = 0o0666;
 pub const F_OK: c_int = 0;
 pub const R_OK: c_int = 4;
 pub const W_OK: c_int = 2;
@@ -621,6 +624,7 @@ pub const _SC_XOPEN_SHM: c_int = 30;
 
 pub const Q_GETQUOTA: c_int = 0x300;
 pub const Q_SETQUOTA: c_int = 0x400;
+pub const Q_SETUSE: c_int = 0x500;
 
 pub const RTLD_GLOBAL: c_int = 0x100;
 
@@ -699,6 +703,12 @@ pub const RB_AUTOBOOT: c_int = 0;
 
 pub const TCP_INFO: c_int = 9;
 
+safe_f! {
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        (fac << 3) | pri
+    }
+}
+
 #[link(name = "util")]
 extern "C" {
     pub fn setgrent();
@@ -1260,7 +1260,27 @@ pub const _SC_SCHED_PRI_MAX: c_int = 2003;
 
 pub const FD_SETSIZE: c_int = 0x100;
 
-pub const ST_NOSUID: c_ulong = 8;
+// <sys/statvfs.h>
+pub const ST_SYNCHRONOUS: c_ulong = 0x00000002;
+pub const ST_NOEXEC: c_ulong = 0x00000004;
+pub const ST_NOSUID: c_ulong = 0x00000008;
+pub const ST_NODEV: c_ulong = 0x00000010;
+pub const ST_UNION: c_ulong = 0x00000020;
+pub const ST_ASYNC: c_ulong = 0x00000040;
+pub const ST_LOCAL: c_ulong = 0x00001000;
+pub const ST_QUOTA: c_ulong = 0x00002000;
+pub const ST_ROOTFS: c_ulong = 0x00004000;
+pub const ST_NOCOREDUMP: c_ulong = 0x00008000;
+pub const ST_RELATIME: c_ulong = 0x00020000;
+pub const ST_IGNORE: c_ulong = 0x00100000;
+pub const ST_DISCARD: c_ulong = 0x00800000;
+pub const ST_EXTATTR: c_ulong = 0x01000000;
+pub const ST_LOG: c_ulong = 0x02000000;
+pub const ST_NOATIME: c_ulong = 0x04000000;
+pub const ST_AUTOMOUNTED: c_ulong = 0x10000000;
+pub const ST_SYMPERM: c_ulong = 0x20000000;
+pub const ST_NODEVMTIME: c_ulong = 0x40000000;
+pub const ST_SOFTDEP: c_ulong = 0x80000000;
 
 // <sys/fstypes.h>
 pub const MNT_UNION: c_int = 0x00000020;
@@ -1600,6 +1620,37 @@ pub const KERN_PROC_NARGV: c_int = 2;
 pub const KERN_PROC_ENV: c_int = 3;
 pub const KERN_PROC_NENV: c_int = 4;
 pub const KERN_PROC_PATHNAME: c_int = 5;
+pub const HW_MACHINE: c_int = 1;
+pub const HW_MODEL: c_int = 2;
+pub const HW_BYTEORDER: c_int = 4;
+pub const HW_PHYSMEM: c_int = 5;
+pub const HW_USERMEM: c_int = 6;
+pub const HW_PAGESIZE: c_int = 7;
+pub const HW_DISKNAMES: c_int = 8;
+pub const HW_IOSTATS: c_int = 9;
+pub const HW_MACHINE_ARCH: c_int = 10;
+pub const HW_ALIGNBYTES: c_int = 11;
+pub const HW_CNMAGIC: c_int = 12;
+pub const HW_PHYSMEM64: c_int = 13;
+pub const HW_USERMEM64: c_int = 14;
+pub const HW_IOSTATNAMES: c_int = 15;
+pub const HW_NCPUONLINE: c_int = 16;
+
+pub const VM_METER: c_int = 1;
+pub const VM_LOADAVG: c_int = 2;
+pub const VM_UVMEXP: c_int = 3;
+pub const VM_NKMEMPAGES: c_int = 4;
+pub const VM_UVMEXP2: c_int = 5;
+pub const VM_ANONMIN: c_int = 6;
+pub const VM_EXECMIN: c_int = 7;
+pub const VM_FILEMIN: c_int = 8;
+pub const VM_MAXSLP: c_int = 9;
+pub const VM_USPACE: c_int = 10;
+pub const VM_ANONMAX: c_int = 11;
+pub const VM_EXECMAX: c_int = 12;
+pub const VM_FILEMAX: c_int = 13;
+pub const VM_MINADDRESS: c_int = 14;
+pub const VM_MAXADDRESS: c_int = 15;
 pub const VM_PROC: c_int = 16;
 pub const VM_PROC_MAP: c_int = 1;
 
@@ -1628,6 +1679,10 @@ pub const SIGEV_NONE: c_int = 0;
 pub const SIGEV_SIGNAL: c_int = 1;
 pub const SIGEV_THREAD: c_int = 2;
 
+pub const SIGPWR: c_int = 32;
+pub const SIGRTMIN: c_int = 33;
+pub const SIGRTMAX: c_int = 63;
+
 pub const WSTOPPED: c_int = 0x00000002; // same as WUNTRACED
 pub const WCONTINUED: c_int = 0x00000010;
 pub const WEXITED: c_int = 0x000000020;
@@ -1689,6 +1744,8 @@ pub const PT_GET_SIGPASS: c_int = 27;
 pub const PT_FIRSTMACH: c_int = 32;
 pub const POSIX_SPAWN_RETURNERROR: c_short = 0x40;
 
+pub const S_IFWHT: crate::mode_t = 0o16_0000;
+
 // Flags for chflags(2)
 pub const SF_APPEND: c_ulong = 0x00040000;
 pub const SF_ARCHIVED: c_ulong = 0x00010000;
@@ -2109,6 +2166,11 @@ extern "C" {
         stackaddr: *mut *mut c_void,
         stacksize: *mut size_t,
     ) -> c_int;
+    pub fn pthread_attr_setstack(
+        attr: *mut crate::pthread_attr_t,
+        stackaddr: *mut c_void,
+        stacksize: size_t,
+    ) -> c_int;
     pub fn pthread_getaffinity_np(
         thread: crate::pthread_t,
         size: size_t,
@@ -2153,6 +2215,7 @@ extern "C" {
         needle: *const c_void,
         needlelen: size_t,
     ) -> *mut c_void;
+    pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
 
     // link.h
 
@@ -2232,6 +2295,15 @@ extern "C" {
     ) -> c_int;
     pub fn getrandom(buf: *mut c_void, buflen: size_t, flags: c_uint) -> ssize_t;
 
+    pub fn posix_spawn_file_actions_addchdir(
+        actions: *mut crate::posix_spawn_file_actions_t,
+        path: *const c_char,
+    ) -> c_int;
+    pub fn posix_spawn_file_actions_addfchdir(
+        actions: *mut crate::posix_spawn_file_actions_t,
+        fd: c_int,
+    ) -> c_int;
+
     pub fn reboot(mode: c_int, bootstr: *mut c_char) -> c_int;
 
     #[link_name = "___lwp_park60"]
@@ -1353,8 +1353,43 @@ pub const CTL_DDB: c_int = 9;
 pub const CTL_VFS: c_int = 10;
 pub const CTL_MAXID: c_int = 11;
 
+pub const HW_MACHINE: c_int = 1;
+pub const HW_MODEL: c_int = 2;
+pub const HW_BYTEORDER: c_int = 4;
+pub const HW_PHYSMEM: c_int = 5;
+pub const HW_USERMEM: c_int = 6;
+pub const HW_PAGESIZE: c_int = 7;
+pub const HW_DISKNAMES: c_int = 8;
+pub const HW_DISKSTATS: c_int = 9;
+pub const HW_DISKCOUNT: c_int = 10;
+pub const HW_SENSORS: c_int = 11;
+pub const HW_CPUSPEED: c_int = 12;
+pub const HW_SETPERF: c_int = 13;
+pub const HW_VENDOR: c_int = 14;
+pub const HW_PRODUCT: c_int = 15;
+pub const HW_VERSION: c_int = 16;
+pub const HW_SERIALNO: c_int = 17;
+pub const HW_UUID: c_int = 18;
+pub const HW_PHYSMEM64: c_int = 19;
+pub const HW_USERMEM64: c_int = 20;
+pub const HW_NCPUFOUND: c_int = 21;
+pub const HW_ALLOWPOWERDOWN: c_int = 22;
+pub const HW_PERFPOLICY: c_int = 23;
+pub const HW_SMT: c_int = 24;
 pub const HW_NCPUONLINE: c_int = 25;
 
+pub const VM_METER: c_int = 1;
+pub const VM_LOADAVG: c_int = 2;
+pub const VM_PSSTRINGS: c_int = 3;
+pub const VM_UVMEXP: c_int = 4;
+pub const VM_SWAPENCRYPT: c_int = 5;
+pub const VM_NKMEMPAGES: c_int = 6;
+pub const VM_ANONMIN: c_int = 7;
+pub const VM_VTEXTMIN: c_int = 8;
+pub const VM_VNODEMIN: c_int = 9;
+pub const VM_MAXSLP: c_int = 10;
+pub const VM_USPACE: c_int = 11;
+
 pub const KERN_OSTYPE: c_int = 1;
 pub const KERN_OSRELEASE: c_int = 2;
 pub const KERN_OSREV: c_int = 3;
@@ -1603,6 +1638,8 @@ pub const PTHREAD_STACK_MIN: size_t = 1_usize << _MAX_PAGE_SHIFT;
 pub const MINSIGSTKSZ: size_t = 3_usize << _MAX_PAGE_SHIFT;
 pub const SIGSTKSZ: size_t = MINSIGSTKSZ + (1_usize << _MAX_PAGE_SHIFT) * 4;
 
+pub const SIGTHR: c_int = 32;
+
 pub const PT_SET_EVENT_MASK: c_int = 12;
 pub const PT_GET_EVENT_MASK: c_int = 13;
 pub const PT_GET_PROCESS_STATE: c_int = 14;
@@ -1913,6 +1950,7 @@ extern "C" {
     pub fn chflags(path: *const c_char, flags: c_uint) -> c_int;
     pub fn fchflags(fd: c_int, flags: c_uint) -> c_int;
     pub fn chflagsat(fd: c_int, path: *const c_char, flags: c_uint, atflag: c_int) -> c_int;
+    pub fn quotactl(path: *const c_char, cmd: c_int, id: c_int, addr: *mut c_char) -> c_int;
     pub fn dirfd(dirp: *mut crate::DIR) -> c_int;
     pub fn getnameinfo(
         sa: *const crate::sockaddr,
@@ -1953,6 +1991,11 @@ extern "C" {
         stackaddr: *mut *mut c_void,
         stacksize: *mut size_t,
     ) -> c_int;
+    pub fn pthread_attr_setstack(
+        attr: *mut crate::pthread_attr_t,
+        stackaddr: *mut c_void,
+        stacksize: size_t,
+    ) -> c_int;
     pub fn pthread_main_np() -> c_int;
     pub fn pthread_get_name_np(tid: crate::pthread_t, name: *mut c_char, len: size_t);
     pub fn pthread_set_name_np(tid: crate::pthread_t, name: *const c_char);
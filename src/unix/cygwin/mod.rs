@@ -890,6 +890,9 @@ pub const S_IRWXO: mode_t = 7;
 pub const S_IROTH: mode_t = 4;
 pub const S_IWOTH: mode_t = 2;
 pub const S_IXOTH: mode_t = 1;
+pub const ACCESSPERMS: mode_t = 511;
+pub const ALLPERMS: mode_t = 4095;
+pub const DEFFILEMODE: mode_t = 438;
 pub const UTIME_NOW: c_long = -2;
 pub const UTIME_OMIT: c_long = -1;
 
@@ -2113,6 +2116,8 @@ extern "C" {
 
     pub fn pthread_attr_getguardsize(attr: *const pthread_attr_t, guardsize: *mut size_t) -> c_int;
 
+    pub fn pthread_attr_setguardsize(attr: *mut pthread_attr_t, guardsize: size_t) -> c_int;
+
     pub fn pthread_attr_getschedparam(
         attr: *const pthread_attr_t,
         param: *mut sched_param,
@@ -2129,6 +2134,12 @@ extern "C" {
         stacksize: *mut size_t,
     ) -> c_int;
 
+    pub fn pthread_attr_setstack(
+        attr: *mut pthread_attr_t,
+        stackaddr: *mut c_void,
+        stacksize: size_t,
+    ) -> c_int;
+
     pub fn pthread_cancel(thread: pthread_t) -> c_int;
 
     pub fn pthread_condattr_getclock(
@@ -619,6 +619,11 @@ pub const R_OK: c_int = 4;
 pub const W_OK: c_int = 2;
 pub const X_OK: c_int = 1;
 
+pub const F_ULOCK: c_int = 0;
+pub const F_LOCK: c_int = 1;
+pub const F_TLOCK: c_int = 2;
+pub const F_TEST: c_int = 3;
+
 pub const SIGHUP: c_int = 1;
 pub const SIGINT: c_int = 2;
 pub const SIGQUIT: c_int = 3;
@@ -888,6 +893,7 @@ pub const MSG_BCAST: c_int = 0x0100;
 pub const MSG_MCAST: c_int = 0x0200;
 pub const MSG_EOF: c_int = 0x0400;
 pub const MSG_NOSIGNAL: c_int = 0x0800;
+pub const MSG_CMSG_CLOEXEC: c_int = 0x1000;
 
 pub const SHUT_RD: c_int = 0;
 pub const SHUT_WR: c_int = 1;
@@ -923,6 +929,9 @@ pub const SS_DISABLE: c_int = 0x2;
 
 pub const FD_SETSIZE: c_int = 1024;
 
+pub const ST_RDONLY: c_ulong = 1;
+pub const ST_NOSUID: c_ulong = 2;
+
 pub const RTLD_LOCAL: c_int = 0x0;
 pub const RTLD_NOW: c_int = 0x1;
 pub const RTLD_GLOBAL: c_int = 0x2;
@@ -1442,6 +1451,14 @@ f! {
     pub fn FD_ZERO(set: *mut fd_set) -> () {
         (*set).fds_bits.fill(0);
     }
+
+    // Haiku does not provide `cfsetspeed`, so set both directions separately.
+    pub fn cfsetspeed(termios: *mut crate::termios, speed: crate::speed_t) -> c_int {
+        if crate::cfsetispeed(termios, speed) != 0 {
+            return -1;
+        }
+        crate::cfsetospeed(termios, speed)
+    }
 }
 
 safe_f! {
@@ -1785,6 +1802,7 @@ extern "C" {
     pub fn ftok(pathname: *const c_char, proj_id: c_int) -> crate::key_t;
 
     pub fn memrchr(cx: *const c_void, c: c_int, n: size_t) -> *mut c_void;
+    pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
 
     pub fn lsearch(
         key: *const c_void,
@@ -3509,10 +3509,13 @@ extern "C" {
 
     pub fn mknodat(dirfd: c_int, pathname: *const c_char, mode: mode_t, dev: dev_t) -> c_int;
 
+    pub fn getumask() -> mode_t;
+
     pub fn __libc_current_sigrtmin() -> c_int;
 
     pub fn __libc_current_sigrtmax() -> c_int;
 
+    pub fn wait3(status: *mut c_int, options: c_int, rusage: *mut crate::rusage) -> crate::pid_t;
     pub fn wait4(
         pid: crate::pid_t,
         status: *mut c_int,
@@ -3878,6 +3881,11 @@ extern "C" {
         __stackaddr: *mut *mut c_void,
         __stacksize: *mut size_t,
     ) -> c_int;
+    pub fn pthread_attr_setstack(
+        __attr: *mut pthread_attr_t,
+        __stackaddr: *mut c_void,
+        __stacksize: size_t,
+    ) -> c_int;
 
     pub fn pthread_mutexattr_getpshared(
         attr: *const pthread_mutexattr_t,
@@ -4241,6 +4249,15 @@ extern "C" {
 
     #[link_name = "__xpg_strerror_r"]
     pub fn strerror_r(__errnum: c_int, __buf: *mut c_char, __buflen: size_t) -> c_int;
+    // Added in `glibc` 2.32
+    #[cfg(libc_glibc_2_32)]
+    pub fn strerrorname_np(__err: c_int) -> *const c_char;
+    #[cfg(libc_glibc_2_32)]
+    pub fn strerrordesc_np(__err: c_int) -> *const c_char;
+    #[cfg(libc_glibc_2_32)]
+    pub fn sigabbrev_np(__sig: c_int) -> *const c_char;
+    #[cfg(libc_glibc_2_32)]
+    pub fn sigdescr_np(__sig: c_int) -> *const c_char;
 
     pub fn __errno_location() -> *mut c_int;
 
@@ -4300,6 +4317,7 @@ extern "C" {
         needlelen: size_t,
     ) -> *mut c_void;
     pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
+    pub fn strsep(string: *mut *mut c_char, delim: *const c_char) -> *mut c_char;
 
     pub fn abs(i: c_int) -> c_int;
     pub fn labs(i: c_long) -> c_long;
@@ -4316,6 +4334,11 @@ extern "C" {
     pub fn seed48(xseed: *mut c_ushort) -> *mut c_ushort;
     pub fn lcong48(p: *mut c_ushort);
 
+    pub fn random() -> c_long;
+    pub fn srandom(seed: c_uint);
+    pub fn initstate(seed: c_uint, state: *mut c_char, size: size_t) -> *mut c_char;
+    pub fn setstate(state: *mut c_char) -> *mut c_char;
+
     pub fn qsort_r(
         base: *mut c_void,
         num: size_t,
@@ -4332,6 +4355,7 @@ extern "C" {
 
     pub fn mallinfo() -> crate::mallinfo;
     pub fn mallinfo2() -> crate::mallinfo2;
+    pub fn malloc_stats();
     pub fn malloc_info(options: c_int, stream: *mut crate::FILE) -> c_int;
     pub fn malloc_usable_size(ptr: *mut c_void) -> size_t;
     pub fn malloc_trim(__pad: size_t) -> c_int;
@@ -4479,6 +4503,10 @@ extern "C" {
 }
 
 safe_f! {
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        fac | pri
+    }
+
     pub const fn makedev(major: c_uint, minor: c_uint) -> crate::dev_t {
         let major = major as crate::dev_t;
         let minor = minor as crate::dev_t;
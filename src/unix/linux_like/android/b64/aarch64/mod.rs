@@ -164,6 +164,36 @@ pub const HWCAP2_SME_FA64: c_ulong = 1 << 30;
 pub const HWCAP2_WFXT: c_ulong = 1 << 31;
 pub const HWCAP2_EBF16: c_ulong = 1 << 32;
 pub const HWCAP2_SVE_EBF16: c_ulong = 1 << 33;
+pub const HWCAP2_CSSC: c_ulong = 1 << 34;
+pub const HWCAP2_RPRFM: c_ulong = 1 << 35;
+pub const HWCAP2_SVE2P1: c_ulong = 1 << 36;
+pub const HWCAP2_SME2: c_ulong = 1 << 37;
+pub const HWCAP2_SME2P1: c_ulong = 1 << 38;
+pub const HWCAP2_SME_I16I32: c_ulong = 1 << 39;
+pub const HWCAP2_SME_BI32I32: c_ulong = 1 << 40;
+pub const HWCAP2_SME_B16B16: c_ulong = 1 << 41;
+pub const HWCAP2_SME_F16F16: c_ulong = 1 << 42;
+pub const HWCAP2_MOPS: c_ulong = 1 << 43;
+pub const HWCAP2_HBC: c_ulong = 1 << 44;
+pub const HWCAP2_SVE_B16B16: c_ulong = 1 << 45;
+pub const HWCAP2_LRCPC3: c_ulong = 1 << 46;
+pub const HWCAP2_LSE128: c_ulong = 1 << 47;
+pub const HWCAP2_FPMR: c_ulong = 1 << 48;
+pub const HWCAP2_LUT: c_ulong = 1 << 49;
+pub const HWCAP2_FAMINMAX: c_ulong = 1 << 50;
+pub const HWCAP2_F8CVT: c_ulong = 1 << 51;
+pub const HWCAP2_F8FMA: c_ulong = 1 << 52;
+pub const HWCAP2_F8DP4: c_ulong = 1 << 53;
+pub const HWCAP2_F8DP2: c_ulong = 1 << 54;
+pub const HWCAP2_F8E4M3: c_ulong = 1 << 55;
+pub const HWCAP2_F8E5M2: c_ulong = 1 << 56;
+pub const HWCAP2_SME_LUTV2: c_ulong = 1 << 57;
+pub const HWCAP2_SME_F8F16: c_ulong = 1 << 58;
+pub const HWCAP2_SME_F8F32: c_ulong = 1 << 59;
+pub const HWCAP2_SME_SF8FMA: c_ulong = 1 << 60;
+pub const HWCAP2_SME_SF8DP4: c_ulong = 1 << 61;
+pub const HWCAP2_SME_SF8DP2: c_ulong = 1 << 62;
+pub const HWCAP2_POE: c_ulong = 1 << 63;
 
 pub const SYS_io_setup: c_long = 0;
 pub const SYS_io_destroy: c_long = 1;
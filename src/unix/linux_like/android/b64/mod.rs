@@ -193,15 +193,6 @@ f! {
     }
 }
 
-extern "C" {
-    pub fn __system_property_wait(
-        pi: *const crate::prop_info,
-        __old_serial: u32,
-        __new_serial_ptr: *mut u32,
-        __relative_timeout: *const crate::timespec,
-    ) -> bool;
-}
-
 cfg_if! {
     if #[cfg(target_arch = "x86_64")] {
         mod x86_64;
@@ -50,6 +50,41 @@ s! {
         __unused4: Padding<c_uint>,
         __unused5: Padding<c_uint>,
     }
+
+    pub struct user_regs_struct {
+        pub pc: c_ulong,
+        pub ra: c_ulong,
+        pub sp: c_ulong,
+        pub gp: c_ulong,
+        pub tp: c_ulong,
+        pub t0: c_ulong,
+        pub t1: c_ulong,
+        pub t2: c_ulong,
+        pub s0: c_ulong,
+        pub s1: c_ulong,
+        pub a0: c_ulong,
+        pub a1: c_ulong,
+        pub a2: c_ulong,
+        pub a3: c_ulong,
+        pub a4: c_ulong,
+        pub a5: c_ulong,
+        pub a6: c_ulong,
+        pub a7: c_ulong,
+        pub s2: c_ulong,
+        pub s3: c_ulong,
+        pub s4: c_ulong,
+        pub s5: c_ulong,
+        pub s6: c_ulong,
+        pub s7: c_ulong,
+        pub s8: c_ulong,
+        pub s9: c_ulong,
+        pub s10: c_ulong,
+        pub s11: c_ulong,
+        pub t3: c_ulong,
+        pub t4: c_ulong,
+        pub t5: c_ulong,
+        pub t6: c_ulong,
+    }
 }
 
 s_no_extra_traits! {
@@ -309,6 +344,8 @@ pub const SYS_perf_event_open: c_long = 241;
 pub const SYS_accept4: c_long = 242;
 pub const SYS_recvmmsg: c_long = 243;
 pub const SYS_arch_specific_syscall: c_long = 244;
+pub const SYS_riscv_hwprobe: c_long = 258;
+pub const SYS_riscv_flush_icache: c_long = 259;
 pub const SYS_wait4: c_long = 260;
 pub const SYS_prlimit64: c_long = 261;
 pub const SYS_fanotify_init: c_long = 262;
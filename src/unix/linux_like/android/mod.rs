@@ -38,6 +38,7 @@ pub type ino64_t = u64;
 pub type __CPU_BITTYPE = c_ulong;
 pub type idtype_t = c_int;
 pub type loff_t = c_longlong;
+pub type fpos64_t = off64_t;
 pub type __kernel_loff_t = c_longlong;
 pub type __kernel_pid_t = c_int;
 
@@ -56,10 +57,45 @@ pub type Elf64_Xword = u64;
 
 pub type eventfd_t = u64;
 
+pub type net_handle_t = u64;
+
+// android/log.h
+c_enum! {
+    pub enum android_LogPriority {
+        pub ANDROID_LOG_UNKNOWN = 0,
+        pub ANDROID_LOG_DEFAULT,
+        pub ANDROID_LOG_VERBOSE,
+        pub ANDROID_LOG_DEBUG,
+        pub ANDROID_LOG_INFO,
+        pub ANDROID_LOG_WARN,
+        pub ANDROID_LOG_ERROR,
+        pub ANDROID_LOG_FATAL,
+        pub ANDROID_LOG_SILENT,
+    }
+
+    pub enum log_id_t {
+        pub LOG_ID_MIN = 0,
+        pub LOG_ID_MAIN = 0,
+        pub LOG_ID_RADIO,
+        pub LOG_ID_EVENTS,
+        pub LOG_ID_SYSTEM,
+        pub LOG_ID_CRASH,
+        pub LOG_ID_STATS,
+        pub LOG_ID_SECURITY,
+        pub LOG_ID_KERNEL,
+        pub LOG_ID_MAX,
+        pub LOG_ID_DEFAULT = 0x7FFFFFFF,
+    }
+}
+
 // these structs sit behind a heap allocation on Android
 pub type posix_spawn_file_actions_t = *mut c_void;
 pub type posix_spawnattr_t = *mut c_void;
 
+extern_ty! {
+    pub type android_namespace_t;
+}
+
 s! {
     pub struct stack_t {
         pub ss_sp: *mut c_void,
@@ -321,6 +357,18 @@ s! {
         pub dlpi_tls_data: *mut c_void,
     }
 
+    // android/dlext.h
+
+    pub struct android_dlextinfo {
+        pub flags: u64,
+        pub reserved_addr: *mut c_void,
+        pub reserved_size: size_t,
+        pub relro_fd: c_int,
+        pub library_fd: c_int,
+        pub library_fd_offset: off64_t,
+        pub library_namespace: *mut android_namespace_t,
+    }
+
     // linux/seccomp.h
     pub struct seccomp_data {
         pub nr: c_int,
@@ -340,6 +388,24 @@ s! {
         pub nr: crate::__s32,
     }
 
+    // linux/prctl.h
+    pub struct prctl_mm_map {
+        pub start_code: crate::__u64,
+        pub end_code: crate::__u64,
+        pub start_data: crate::__u64,
+        pub end_data: crate::__u64,
+        pub start_brk: crate::__u64,
+        pub brk: crate::__u64,
+        pub start_stack: crate::__u64,
+        pub arg_start: crate::__u64,
+        pub arg_end: crate::__u64,
+        pub env_start: crate::__u64,
+        pub env_end: crate::__u64,
+        pub auxv: *mut crate::__u64,
+        pub auxv_size: crate::__u32,
+        pub exe_fd: crate::__u32,
+    }
+
     // linux/input.h
     pub struct input_event {
         pub time: crate::timeval,
@@ -915,6 +981,10 @@ pub const RENAME_NOREPLACE: c_int = 1;
 pub const RENAME_EXCHANGE: c_int = 2;
 pub const RENAME_WHITEOUT: c_int = 4;
 
+// linux/close_range.h
+pub const CLOSE_RANGE_UNSHARE: c_uint = 1 << 1;
+pub const CLOSE_RANGE_CLOEXEC: c_uint = 1 << 2;
+
 pub const FIOCLEX: c_int = 0x5451;
 pub const FIONCLEX: c_int = 0x5450;
 
@@ -1311,6 +1381,20 @@ pub const ST_RELATIME: c_ulong = 4096;
 pub const RTLD_NOLOAD: c_int = 0x4;
 pub const RTLD_NODELETE: c_int = 0x1000;
 
+// android/dlext.h
+pub const ANDROID_DLEXT_RESERVED_ADDRESS: u64 = 0x1;
+pub const ANDROID_DLEXT_RESERVED_ADDRESS_HINT: u64 = 0x2;
+pub const ANDROID_DLEXT_WRITE_RELRO: u64 = 0x4;
+pub const ANDROID_DLEXT_USE_RELRO: u64 = 0x8;
+pub const ANDROID_DLEXT_USE_LIBRARY_FD: u64 = 0x10;
+pub const ANDROID_DLEXT_USE_LIBRARY_FD_OFFSET: u64 = 0x20;
+pub const ANDROID_DLEXT_FORCE_LOAD: u64 = 0x40;
+pub const ANDROID_DLEXT_RESERVED_ADDRESS_RECURSIVE: u64 = 0x100;
+pub const ANDROID_DLEXT_USE_NAMESPACE: u64 = 0x200;
+
+// android/multinetwork.h
+pub const NETWORK_UNSPECIFIED: net_handle_t = 0;
+
 pub const SEM_FAILED: *mut sem_t = ptr::null_mut();
 
 pub const AI_PASSIVE: c_int = 0x00000001;
@@ -3287,7 +3371,33 @@ pub const NET_LLC: c_int = 18;
 pub const NET_NETFILTER: c_int = 19;
 pub const NET_DCCP: c_int = 20;
 pub const HUGETLB_FLAG_ENCODE_SHIFT: c_int = 26;
+pub const HUGETLB_FLAG_ENCODE_MASK: c_int = 0x3f;
+pub const HUGETLB_FLAG_ENCODE_64KB: c_int = 16 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_512KB: c_int = 19 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_1MB: c_int = 20 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_2MB: c_int = 21 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_8MB: c_int = 23 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_16MB: c_int = 24 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_32MB: c_int = 25 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_256MB: c_int = 28 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_512MB: c_int = 29 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_1GB: c_int = 30 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_2GB: c_int = 31 << HUGETLB_FLAG_ENCODE_SHIFT;
+pub const HUGETLB_FLAG_ENCODE_16GB: c_int = 34 << HUGETLB_FLAG_ENCODE_SHIFT;
 pub const MAP_HUGE_SHIFT: c_int = HUGETLB_FLAG_ENCODE_SHIFT;
+pub const MAP_HUGE_MASK: c_int = HUGETLB_FLAG_ENCODE_MASK;
+pub const MAP_HUGE_64KB: c_int = HUGETLB_FLAG_ENCODE_64KB;
+pub const MAP_HUGE_512KB: c_int = HUGETLB_FLAG_ENCODE_512KB;
+pub const MAP_HUGE_1MB: c_int = HUGETLB_FLAG_ENCODE_1MB;
+pub const MAP_HUGE_2MB: c_int = HUGETLB_FLAG_ENCODE_2MB;
+pub const MAP_HUGE_8MB: c_int = HUGETLB_FLAG_ENCODE_8MB;
+pub const MAP_HUGE_16MB: c_int = HUGETLB_FLAG_ENCODE_16MB;
+pub const MAP_HUGE_32MB: c_int = HUGETLB_FLAG_ENCODE_32MB;
+pub const MAP_HUGE_256MB: c_int = HUGETLB_FLAG_ENCODE_256MB;
+pub const MAP_HUGE_512MB: c_int = HUGETLB_FLAG_ENCODE_512MB;
+pub const MAP_HUGE_1GB: c_int = HUGETLB_FLAG_ENCODE_1GB;
+pub const MAP_HUGE_2GB: c_int = HUGETLB_FLAG_ENCODE_2GB;
+pub const MAP_HUGE_16GB: c_int = HUGETLB_FLAG_ENCODE_16GB;
 
 // include/linux/sched.h
 pub const PF_VCPU: c_int = 0x00000001;
@@ -3458,6 +3568,10 @@ safe_f! {
     pub const fn minor(dev: crate::dev_t) -> c_int {
         ((dev & 0xff) | ((dev >> 12) & 0xfff00)) as c_int
     }
+
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        fac | pri
+    }
 }
 
 extern "C" {
@@ -3467,6 +3581,24 @@ extern "C" {
     pub fn setgrent();
     pub fn endgrent();
     pub fn getgrent() -> *mut crate::group;
+    pub fn quick_exit(status: c_int) -> !;
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
+
+    pub fn drand48() -> c_double;
+    pub fn erand48(xseed: *mut c_ushort) -> c_double;
+    pub fn lrand48() -> c_long;
+    pub fn nrand48(xseed: *mut c_ushort) -> c_long;
+    pub fn mrand48() -> c_long;
+    pub fn jrand48(xseed: *mut c_ushort) -> c_long;
+    pub fn srand48(seed: c_long);
+    pub fn seed48(xseed: *mut c_ushort) -> *mut c_ushort;
+    pub fn lcong48(p: *mut c_ushort);
+
+    pub fn random() -> c_long;
+    pub fn srandom(seed: c_uint);
+    pub fn initstate(seed: c_uint, state: *mut c_char, size: size_t) -> *mut c_char;
+    pub fn setstate(state: *mut c_char) -> *mut c_char;
+
     pub fn getrlimit64(resource: c_int, rlim: *mut rlimit64) -> c_int;
     pub fn setrlimit64(resource: c_int, rlim: *const rlimit64) -> c_int;
     pub fn getrlimit(resource: c_int, rlim: *mut crate::rlimit) -> c_int;
@@ -3627,12 +3759,25 @@ extern "C" {
         maxevents: c_int,
         timeout: c_int,
     ) -> c_int;
+    pub fn epoll_pwait(
+        epfd: c_int,
+        events: *mut crate::epoll_event,
+        maxevents: c_int,
+        timeout: c_int,
+        sigmask: *const crate::sigset_t,
+    ) -> c_int;
     pub fn epoll_ctl(epfd: c_int, op: c_int, fd: c_int, event: *mut crate::epoll_event) -> c_int;
     pub fn unshare(flags: c_int) -> c_int;
     pub fn umount(target: *const c_char) -> c_int;
     pub fn sched_get_priority_max(policy: c_int) -> c_int;
     pub fn tee(fd_in: c_int, fd_out: c_int, len: size_t, flags: c_uint) -> ssize_t;
     pub fn settimeofday(tv: *const crate::timeval, tz: *const crate::timezone) -> c_int;
+    /// Only available in API Version 26+
+    pub fn futimes(fd: c_int, times: *const crate::timeval) -> c_int;
+    /// Only available in API Version 26+
+    pub fn lutimes(file: *const c_char, times: *const crate::timeval) -> c_int;
+    /// Only available in API Version 26+
+    pub fn futimesat(dirfd: c_int, path: *const c_char, times: *const crate::timeval) -> c_int;
     pub fn splice(
         fd_in: c_int,
         off_in: *mut crate::loff_t,
@@ -3646,6 +3791,13 @@ extern "C" {
     pub fn eventfd_write(fd: c_int, value: eventfd_t) -> c_int;
     pub fn sched_rr_get_interval(pid: crate::pid_t, tp: *mut crate::timespec) -> c_int;
     pub fn sem_timedwait(sem: *mut sem_t, abstime: *const crate::timespec) -> c_int;
+    /// Only available in API Version 30+
+    #[cfg(libc_android_api_30)]
+    pub fn sem_clockwait(
+        sem: *mut sem_t,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
     pub fn sem_getvalue(sem: *mut sem_t, sval: *mut c_int) -> c_int;
     pub fn sched_setparam(pid: crate::pid_t, param: *const crate::sched_param) -> c_int;
     pub fn setns(fd: c_int, nstype: c_int) -> c_int;
@@ -3768,8 +3920,42 @@ extern "C" {
 
     pub fn getauxval(type_: c_ulong) -> c_ulong;
 
+    /// Only available in API Version 23+
+    pub fn mkfifoat(dirfd: c_int, pathname: *const c_char, mode: crate::mode_t) -> c_int;
+
+    /// Only available in API Version 21+
+    pub fn mkstemp64(template: *mut c_char) -> c_int;
+    /// Only available in API Version 23+
+    pub fn mkostemp64(template: *mut c_char, flags: c_int) -> c_int;
+    /// Only available in API Version 23+
+    pub fn mkstemps64(template: *mut c_char, suffixlen: c_int) -> c_int;
+    /// Only available in API Version 23+
+    pub fn mkostemps64(template: *mut c_char, suffixlen: c_int, flags: c_int) -> c_int;
+
+    /// Only available in API Version 24+
+    pub fn fopen64(filename: *const c_char, mode: *const c_char) -> *mut crate::FILE;
+    /// Only available in API Version 24+
+    pub fn freopen64(
+        filename: *const c_char,
+        mode: *const c_char,
+        file: *mut crate::FILE,
+    ) -> *mut crate::FILE;
+    /// Only available in API Version 24+
+    pub fn tmpfile64() -> *mut crate::FILE;
+    /// Only available in API Version 24+
+    pub fn fgetpos64(stream: *mut crate::FILE, ptr: *mut fpos64_t) -> c_int;
+    /// Only available in API Version 24+
+    pub fn fsetpos64(stream: *mut crate::FILE, ptr: *const fpos64_t) -> c_int;
+    /// Only available in API Version 24+
+    pub fn fseeko64(stream: *mut crate::FILE, offset: off64_t, whence: c_int) -> c_int;
+    /// Only available in API Version 24+
+    pub fn ftello64(stream: *mut crate::FILE) -> off64_t;
+    /// Only available in API Version 24+
+    pub fn lockf64(fd: c_int, cmd: c_int, len: off64_t) -> c_int;
+
     /// Only available in API Version 28+
     pub fn getrandom(buf: *mut c_void, buflen: size_t, flags: c_uint) -> ssize_t;
+    /// Only available in API Version 28+
     pub fn getentropy(buf: *mut c_void, buflen: size_t) -> c_int;
 
     pub fn __system_property_set(__name: *const c_char, __value: *const c_char) -> c_int;
@@ -3780,6 +3966,24 @@ extern "C" {
         __callback: unsafe extern "C" fn(__pi: *const prop_info, __cookie: *mut c_void),
         __cookie: *mut c_void,
     ) -> c_int;
+    /// Only available in API Version 26+
+    pub fn __system_property_read_callback(
+        __pi: *const prop_info,
+        __callback: unsafe extern "C" fn(
+            __cookie: *mut c_void,
+            __name: *const c_char,
+            __value: *const c_char,
+            __serial: u32,
+        ),
+        __cookie: *mut c_void,
+    );
+    /// Only available in API Version 26+
+    pub fn __system_property_wait(
+        __pi: *const prop_info,
+        __old_serial: u32,
+        __new_serial_ptr: *mut u32,
+        __relative_timeout: *const crate::timespec,
+    ) -> bool;
 
     // #include <link.h>
     /// Only available in API Version 21+
@@ -3790,6 +3994,13 @@ extern "C" {
         data: *mut c_void,
     ) -> c_int;
 
+    // #include <android/dlext.h>
+    pub fn android_dlopen_ext(
+        filename: *const c_char,
+        flags: c_int,
+        extinfo: *const android_dlextinfo,
+    ) -> *mut c_void;
+
     pub fn arc4random() -> u32;
     pub fn arc4random_uniform(__upper_bound: u32) -> u32;
     pub fn arc4random_buf(__buf: *mut c_void, __n: size_t);
@@ -3832,7 +4043,9 @@ extern "C" {
 
     pub fn klogctl(syslog_type: c_int, bufp: *mut c_char, len: c_int) -> c_int;
 
+    /// Only available in API Version 30+
     pub fn memfd_create(name: *const c_char, flags: c_uint) -> c_int;
+    /// Only available in API Version 30+
     pub fn renameat2(
         olddirfd: c_int,
         oldpath: *const c_char,
@@ -3840,6 +4053,83 @@ extern "C" {
         newpath: *const c_char,
         flags: c_uint,
     ) -> c_int;
+
+    /// Only available in API Version 31+
+    #[cfg(libc_android_api_31)]
+    pub fn pidfd_open(pid: crate::pid_t, flags: c_uint) -> c_int;
+    /// Only available in API Version 31+
+    #[cfg(libc_android_api_31)]
+    pub fn pidfd_getfd(pidfd: c_int, targetfd: c_int, flags: c_uint) -> c_int;
+    /// Only available in API Version 31+
+    #[cfg(libc_android_api_31)]
+    pub fn pidfd_send_signal(
+        pidfd: c_int,
+        sig: c_int,
+        info: *mut crate::siginfo_t,
+        flags: c_uint,
+    ) -> c_int;
+    /// Only available in API Version 31+
+    #[cfg(libc_android_api_31)]
+    pub fn process_madvise(
+        pidfd: c_int,
+        iov: *const crate::iovec,
+        iovcnt: size_t,
+        advice: c_int,
+        flags: c_uint,
+    ) -> ssize_t;
+
+    /// Only available in API Version 34+
+    #[cfg(libc_android_api_34)]
+    pub fn close_range(first: c_uint, last: c_uint, flags: c_int) -> c_int;
+    /// Only available in API Version 34+
+    #[cfg(libc_android_api_34)]
+    pub fn copy_file_range(
+        fd_in: c_int,
+        off_in: *mut crate::off64_t,
+        fd_out: c_int,
+        off_out: *mut crate::off64_t,
+        len: size_t,
+        flags: c_uint,
+    ) -> ssize_t;
+    /// Only available in API Version 35+
+    #[cfg(libc_android_api_35)]
+    pub fn epoll_pwait2(
+        epfd: c_int,
+        events: *mut crate::epoll_event,
+        maxevents: c_int,
+        timeout: *const crate::timespec,
+        sigmask: *const crate::sigset_t,
+    ) -> c_int;
+}
+
+// #include <android/log.h>
+#[link(name = "log")]
+extern "C" {
+    pub fn __android_log_write(prio: c_int, tag: *const c_char, text: *const c_char) -> c_int;
+    pub fn __android_log_print(prio: c_int, tag: *const c_char, fmt: *const c_char, ...) -> c_int;
+    pub fn __android_log_buf_write(
+        buf_id: c_int,
+        prio: c_int,
+        tag: *const c_char,
+        text: *const c_char,
+    ) -> c_int;
+}
+
+// #include <android/multinetwork.h>
+#[link(name = "android")]
+extern "C" {
+    /// Only available in API Version 23+
+    pub fn android_setsocknetwork(network: net_handle_t, fd: c_int) -> c_int;
+    /// Only available in API Version 23+
+    pub fn android_setprocnetwork(network: net_handle_t) -> c_int;
+    /// Only available in API Version 23+
+    pub fn android_getaddrinfofornetwork(
+        network: net_handle_t,
+        node: *const c_char,
+        service: *const c_char,
+        hints: *const crate::addrinfo,
+        res: *mut *mut crate::addrinfo,
+    ) -> c_int;
 }
 
 cfg_if! {
@@ -1342,12 +1342,35 @@ extern "C" {
     pub fn getrlimit(resource: c_int, rlim: *mut crate::rlimit) -> c_int;
     pub fn setrlimit(resource: c_int, rlim: *const crate::rlimit) -> c_int;
     pub fn strerror_r(errnum: c_int, buf: *mut c_char, buflen: size_t) -> c_int;
+    pub fn memmem(
+        haystack: *const c_void,
+        haystacklen: size_t,
+        needle: *const c_void,
+        needlelen: size_t,
+    ) -> *mut c_void;
+    pub fn quick_exit(status: c_int) -> !;
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
 
     pub fn abs(i: c_int) -> c_int;
     pub fn labs(i: c_long) -> c_long;
     pub fn rand() -> c_int;
     pub fn srand(seed: c_uint);
 
+    pub fn drand48() -> c_double;
+    pub fn erand48(xseed: *mut c_ushort) -> c_double;
+    pub fn lrand48() -> c_long;
+    pub fn nrand48(xseed: *mut c_ushort) -> c_long;
+    pub fn mrand48() -> c_long;
+    pub fn jrand48(xseed: *mut c_ushort) -> c_long;
+    pub fn srand48(seed: c_long);
+    pub fn seed48(xseed: *mut c_ushort) -> *mut c_ushort;
+    pub fn lcong48(p: *mut c_ushort);
+
+    pub fn random() -> c_long;
+    pub fn srandom(seed: c_uint);
+    pub fn initstate(seed: c_uint, state: *mut c_char, size: size_t) -> *mut c_char;
+    pub fn setstate(state: *mut c_char) -> *mut c_char;
+
     pub fn gettimeofday(tp: *mut crate::timeval, tz: *mut c_void) -> c_int;
 
     pub fn setpwent();
@@ -522,6 +522,8 @@ extern "C" {
     pub fn getrlimit(resource: crate::__rlimit_resource_t, rlim: *mut crate::rlimit) -> c_int;
     pub fn setrlimit(resource: crate::__rlimit_resource_t, rlim: *const crate::rlimit) -> c_int;
     pub fn getauxval(type_: c_ulong) -> c_ulong;
+    pub fn getpriority(which: crate::__priority_which_t, who: crate::id_t) -> c_int;
+    pub fn setpriority(which: crate::__priority_which_t, who: crate::id_t, prio: c_int) -> c_int;
 
 }
 
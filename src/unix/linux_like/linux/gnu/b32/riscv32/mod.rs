@@ -749,6 +749,8 @@ pub const SYS_pwritev: c_long = 70;
 pub const SYS_rt_tgsigqueueinfo: c_long = 240;
 pub const SYS_perf_event_open: c_long = 241;
 pub const SYS_recvmmsg: c_long = 243;
+pub const SYS_riscv_hwprobe: c_long = 258;
+pub const SYS_riscv_flush_icache: c_long = 259;
 pub const SYS_fanotify_init: c_long = 262;
 pub const SYS_fanotify_mark: c_long = 263;
 pub const SYS_prlimit64: c_long = 261;
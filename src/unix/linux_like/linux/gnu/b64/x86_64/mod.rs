@@ -723,11 +723,40 @@ pub const REG_TRAPNO: c_int = 20;
 pub const REG_OLDMASK: c_int = 21;
 pub const REG_CR2: c_int = 22;
 
+// asm/prctl.h
+pub const ARCH_SET_GS: c_int = 0x1001;
+pub const ARCH_SET_FS: c_int = 0x1002;
+pub const ARCH_GET_FS: c_int = 0x1003;
+pub const ARCH_GET_GS: c_int = 0x1004;
+pub const ARCH_GET_CPUID: c_int = 0x1011;
+pub const ARCH_SET_CPUID: c_int = 0x1012;
+pub const ARCH_GET_XCOMP_SUPP: c_int = 0x1021;
+pub const ARCH_GET_XCOMP_PERM: c_int = 0x1022;
+pub const ARCH_REQ_XCOMP_PERM: c_int = 0x1023;
+pub const ARCH_GET_XCOMP_GUEST_PERM: c_int = 0x1024;
+pub const ARCH_REQ_XCOMP_GUEST_PERM: c_int = 0x1025;
+pub const ARCH_MAP_VDSO_X32: c_int = 0x2001;
+pub const ARCH_MAP_VDSO_32: c_int = 0x2002;
+pub const ARCH_MAP_VDSO_64: c_int = 0x2003;
+pub const ARCH_GET_UNTAG_MASK: c_int = 0x4001;
+pub const ARCH_ENABLE_TAGGED_ADDR: c_int = 0x4002;
+pub const ARCH_GET_MAX_TAG_BITS: c_int = 0x4003;
+pub const ARCH_FORCE_TAGGED_SVA: c_int = 0x4004;
+pub const ARCH_SHSTK_ENABLE: c_int = 0x5001;
+pub const ARCH_SHSTK_DISABLE: c_int = 0x5002;
+pub const ARCH_SHSTK_LOCK: c_int = 0x5003;
+pub const ARCH_SHSTK_UNLOCK: c_int = 0x5004;
+pub const ARCH_SHSTK_STATUS: c_int = 0x5005;
+pub const ARCH_SHSTK_SHSTK: c_ulong = 1 << 0;
+pub const ARCH_SHSTK_WRSS: c_ulong = 1 << 1;
+
 extern "C" {
     pub fn getcontext(ucp: *mut ucontext_t) -> c_int;
     pub fn setcontext(ucp: *const ucontext_t) -> c_int;
     pub fn makecontext(ucp: *mut ucontext_t, func: extern "C" fn(), argc: c_int, ...);
     pub fn swapcontext(uocp: *mut ucontext_t, ucp: *const ucontext_t) -> c_int;
+
+    pub fn arch_prctl(code: c_int, addr: c_ulong) -> c_int;
 }
 
 cfg_if! {
@@ -147,6 +147,17 @@ s! {
         pub ch_addralign: crate::Elf32_Word,
     }
 
+    pub struct Dl_serpath {
+        pub dls_name: *mut c_char,
+        pub dls_flags: c_uint,
+    }
+
+    pub struct Dl_serinfo {
+        pub dls_size: size_t,
+        pub dls_cnt: c_uint,
+        pub dls_serpath: [Dl_serpath; 1],
+    }
+
     pub struct seminfo {
         pub semmap: c_int,
         pub semmni: c_int,
@@ -348,6 +359,24 @@ s! {
         __state: crate::mbstate_t,
     }
 
+    pub struct random_data {
+        pub fptr: *mut i32,
+        pub rptr: *mut i32,
+        pub state: *mut i32,
+        pub rand_type: c_int,
+        pub rand_deg: c_int,
+        pub rand_sep: c_int,
+        pub end_ptr: *mut i32,
+    }
+
+    pub struct drand48_data {
+        __x: [c_ushort; 3],
+        __old_x: [c_ushort; 3],
+        __c: c_ushort,
+        __init: c_ushort,
+        __a: c_ulonglong,
+    }
+
     // linux x32 compatibility
     // See https://sourceware.org/bugzilla/show_bug.cgi?id=16437
     #[derive(Default)]
@@ -523,6 +552,17 @@ s_no_extra_traits! {
         pub exit: __c_anonymous_ptrace_syscall_info_exit,
         pub seccomp: __c_anonymous_ptrace_syscall_info_seccomp,
     }
+
+    /// Argument for `semctl` commands that take one.
+    ///
+    /// It must be passed to `semctl` by value as the fourth argument, not as a
+    /// pointer. [`semctl_semun`] does this with the correct type.
+    pub union semun {
+        pub val: c_int,
+        pub buf: *mut crate::semid_ds,
+        pub array: *mut c_ushort,
+        pub __buf: *mut crate::seminfo,
+    }
 }
 
 cfg_if! {
@@ -548,6 +588,18 @@ cfg_if! {
                 }
             }
         }
+
+        impl PartialEq for semun {
+            fn eq(&self, other: &semun) -> bool {
+                unsafe { self.val == other.val }
+            }
+        }
+        impl Eq for semun {}
+        impl hash::Hash for semun {
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                unsafe { self.val.hash(state) };
+            }
+        }
     }
 }
 
@@ -624,6 +676,7 @@ pub const RTLD_DI_PROFILENAME: c_int = 7;
 pub const RTLD_DI_PROFILEOUT: c_int = 8;
 pub const RTLD_DI_TLS_MODID: c_int = 9;
 pub const RTLD_DI_TLS_DATA: c_int = 10;
+pub const RTLD_DI_PHDR: c_int = 11;
 
 pub const SOCK_NONBLOCK: c_int = O_NONBLOCK;
 
@@ -944,8 +997,6 @@ pub const GLOB_TILDE: c_int = 1 << 12;
 pub const GLOB_ONLYDIR: c_int = 1 << 13;
 pub const GLOB_TILDE_CHECK: c_int = 1 << 14;
 
-pub const MADV_COLLAPSE: c_int = 25;
-
 cfg_if! {
     if #[cfg(any(
         target_arch = "arm",
@@ -963,6 +1014,7 @@ cfg_if! {
     }
 }
 pub const PTHREAD_MUTEX_ADAPTIVE_NP: c_int = 3;
+pub const PTHREAD_ATTR_NO_SIGMASK_NP: c_int = -1;
 
 pub const REG_STARTEND: c_int = 4;
 
@@ -970,6 +1022,19 @@ pub const REG_EEND: c_int = 14;
 pub const REG_ESIZE: c_int = 15;
 pub const REG_ERPAREN: c_int = 16;
 
+f! {
+    /// Call `semctl` with a [`semun`] argument, passed by value.
+    pub fn semctl_semun(semid: c_int, semnum: c_int, cmd: c_int, arg: semun) -> c_int {
+        crate::semctl(semid, semnum, cmd, arg)
+    }
+}
+
+safe_f! {
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        fac | pri
+    }
+}
+
 extern "C" {
     pub fn fgetspent_r(
         fp: *mut crate::FILE,
@@ -998,6 +1063,31 @@ extern "C" {
         compar: Option<unsafe extern "C" fn(*const c_void, *const c_void, *mut c_void) -> c_int>,
         arg: *mut c_void,
     );
+    pub fn on_exit(function: extern "C" fn(c_int, *mut c_void), arg: *mut c_void) -> c_int;
+    pub fn random_r(buf: *mut random_data, result: *mut i32) -> c_int;
+    pub fn srandom_r(seed: c_uint, buf: *mut random_data) -> c_int;
+    pub fn initstate_r(
+        seed: c_uint,
+        statebuf: *mut c_char,
+        statelen: size_t,
+        buf: *mut random_data,
+    ) -> c_int;
+    pub fn setstate_r(statebuf: *mut c_char, buf: *mut random_data) -> c_int;
+    pub fn drand48_r(buffer: *mut drand48_data, result: *mut c_double) -> c_int;
+    pub fn erand48_r(
+        xsubi: *mut c_ushort,
+        buffer: *mut drand48_data,
+        result: *mut c_double,
+    ) -> c_int;
+    pub fn lrand48_r(buffer: *mut drand48_data, result: *mut c_long) -> c_int;
+    pub fn nrand48_r(xsubi: *mut c_ushort, buffer: *mut drand48_data, result: *mut c_long)
+        -> c_int;
+    pub fn mrand48_r(buffer: *mut drand48_data, result: *mut c_long) -> c_int;
+    pub fn jrand48_r(xsubi: *mut c_ushort, buffer: *mut drand48_data, result: *mut c_long)
+        -> c_int;
+    pub fn srand48_r(seedval: c_long, buffer: *mut drand48_data) -> c_int;
+    pub fn seed48_r(seed16v: *mut c_ushort, buffer: *mut drand48_data) -> c_int;
+    pub fn lcong48_r(param: *mut c_ushort, buffer: *mut drand48_data) -> c_int;
     #[cfg_attr(gnu_time_bits64, link_name = "__sendmmsg64")]
     pub fn sendmmsg(
         sockfd: c_int,
@@ -1110,6 +1200,17 @@ extern "C" {
     // Added in `glibc` 2.29
     pub fn reallocarray(ptr: *mut c_void, nmemb: size_t, size: size_t) -> *mut c_void;
 
+    // string.h
+    // Added in `glibc` 2.32
+    #[cfg(libc_glibc_2_32)]
+    pub fn strerrorname_np(errnum: c_int) -> *const c_char;
+    #[cfg(libc_glibc_2_32)]
+    pub fn strerrordesc_np(errnum: c_int) -> *const c_char;
+    #[cfg(libc_glibc_2_32)]
+    pub fn sigabbrev_np(sig: c_int) -> *const c_char;
+    #[cfg(libc_glibc_2_32)]
+    pub fn sigdescr_np(sig: c_int) -> *const c_char;
+
     pub fn ctermid(s: *mut c_char) -> *mut c_char;
     pub fn backtrace(buf: *mut *mut c_void, sz: c_int) -> c_int;
     pub fn backtrace_symbols(buffer: *const *mut c_void, len: c_int) -> *mut *mut c_char;
@@ -1134,6 +1235,20 @@ extern "C" {
         cpusetsize: size_t,
         cpuset: *const crate::cpu_set_t,
     ) -> c_int;
+    pub fn pthread_getattr_default_np(attr: *mut crate::pthread_attr_t) -> c_int;
+    pub fn pthread_setattr_default_np(attr: *const crate::pthread_attr_t) -> c_int;
+    // Added in `glibc` 2.32
+    #[cfg(libc_glibc_2_32)]
+    pub fn pthread_attr_getsigmask_np(
+        attr: *const crate::pthread_attr_t,
+        sigmask: *mut crate::sigset_t,
+    ) -> c_int;
+    // Added in `glibc` 2.32
+    #[cfg(libc_glibc_2_32)]
+    pub fn pthread_attr_setsigmask_np(
+        attr: *mut crate::pthread_attr_t,
+        sigmask: *const crate::sigset_t,
+    ) -> c_int;
     pub fn getpriority(which: crate::__priority_which_t, who: crate::id_t) -> c_int;
     pub fn setpriority(which: crate::__priority_which_t, who: crate::id_t, prio: c_int) -> c_int;
     pub fn pthread_rwlockattr_getkind_np(
@@ -1155,11 +1270,86 @@ extern "C" {
         retval: *mut *mut c_void,
         abstime: *const crate::timespec,
     ) -> c_int;
+    // Added in `glibc` 2.31
+    #[cfg(libc_glibc_2_31)]
+    #[cfg_attr(
+        all(target_pointer_width = "32", gnu_time_bits64),
+        link_name = "__pthread_clockjoin_np64"
+    )]
+    pub fn pthread_clockjoin_np(
+        thread: crate::pthread_t,
+        retval: *mut *mut c_void,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
+    // Added in `glibc` 2.30
+    #[cfg(libc_glibc_2_30)]
+    #[cfg_attr(
+        all(target_pointer_width = "32", gnu_time_bits64),
+        link_name = "__pthread_cond_clockwait64"
+    )]
+    pub fn pthread_cond_clockwait(
+        cond: *mut crate::pthread_cond_t,
+        mutex: *mut crate::pthread_mutex_t,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
+    #[cfg(libc_glibc_2_30)]
+    #[cfg_attr(
+        all(target_pointer_width = "32", gnu_time_bits64),
+        link_name = "__pthread_mutex_clocklock64"
+    )]
+    pub fn pthread_mutex_clocklock(
+        mutex: *mut crate::pthread_mutex_t,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
+    #[cfg(libc_glibc_2_30)]
+    #[cfg_attr(
+        all(target_pointer_width = "32", gnu_time_bits64),
+        link_name = "__pthread_rwlock_clockrdlock64"
+    )]
+    pub fn pthread_rwlock_clockrdlock(
+        rwlock: *mut crate::pthread_rwlock_t,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
+    #[cfg(libc_glibc_2_30)]
+    #[cfg_attr(
+        all(target_pointer_width = "32", gnu_time_bits64),
+        link_name = "__pthread_rwlock_clockwrlock64"
+    )]
+    pub fn pthread_rwlock_clockwrlock(
+        rwlock: *mut crate::pthread_rwlock_t,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
+    #[cfg(libc_glibc_2_30)]
+    #[cfg_attr(
+        all(target_pointer_width = "32", gnu_time_bits64),
+        link_name = "__sem_clockwait64"
+    )]
+    pub fn sem_clockwait(
+        sem: *mut crate::sem_t,
+        clockid: crate::clockid_t,
+        abstime: *const crate::timespec,
+    ) -> c_int;
     pub fn mallinfo() -> crate::mallinfo;
     pub fn mallinfo2() -> crate::mallinfo2;
     pub fn malloc_stats();
     pub fn malloc_info(options: c_int, stream: *mut crate::FILE) -> c_int;
     pub fn malloc_usable_size(ptr: *mut c_void) -> size_t;
+
+    // Not declared in any header, exported by glibc so that replacement allocators can forward to
+    // the builtin one.
+    pub fn __libc_malloc(size: size_t) -> *mut c_void;
+    pub fn __libc_calloc(nmemb: size_t, size: size_t) -> *mut c_void;
+    pub fn __libc_realloc(ptr: *mut c_void, size: size_t) -> *mut c_void;
+    pub fn __libc_free(ptr: *mut c_void);
+    pub fn __libc_memalign(alignment: size_t, size: size_t) -> *mut c_void;
+    pub fn __libc_valloc(size: size_t) -> *mut c_void;
+    pub fn __libc_pvalloc(size: size_t) -> *mut c_void;
+
     pub fn getpwent_r(
         pwd: *mut crate::passwd,
         buf: *mut c_char,
@@ -1305,6 +1495,81 @@ extern "C" {
     pub fn mempcpy(dest: *mut c_void, src: *const c_void, n: size_t) -> *mut c_void;
 
     pub fn tgkill(tgid: crate::pid_t, tid: crate::pid_t, sig: c_int) -> c_int;
+
+    // sys/sysinfo.h
+    pub fn get_nprocs_conf() -> c_int;
+    pub fn get_nprocs() -> c_int;
+    pub fn get_phys_pages() -> c_long;
+    pub fn get_avphys_pages() -> c_long;
+}
+
+// sys/rseq.h
+// Added in `glibc` 2.35
+#[cfg(libc_glibc_2_35)]
+extern "C" {
+    pub static __rseq_offset: crate::ptrdiff_t;
+    pub static __rseq_size: c_uint;
+    pub static __rseq_flags: c_uint;
+}
+
+// resolv.h
+// Moved from `libresolv` to `libc` in `glibc` 2.34
+#[cfg(libc_glibc_2_34)]
+extern "C" {
+    pub fn res_query(
+        dname: *const c_char,
+        class: c_int,
+        type_: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn res_search(
+        dname: *const c_char,
+        class: c_int,
+        type_: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn res_querydomain(
+        name: *const c_char,
+        domain: *const c_char,
+        class: c_int,
+        type_: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn res_mkquery(
+        op: c_int,
+        dname: *const c_char,
+        class: c_int,
+        type_: c_int,
+        data: *const c_uchar,
+        datalen: c_int,
+        newrr: *const c_uchar,
+        buf: *mut c_uchar,
+        buflen: c_int,
+    ) -> c_int;
+    pub fn res_send(
+        msg: *const c_uchar,
+        msglen: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn dn_comp(
+        src: *const c_char,
+        dst: *mut c_uchar,
+        space: c_int,
+        dnptrs: *mut *mut c_uchar,
+        lastdnptr: *mut *mut c_uchar,
+    ) -> c_int;
+    pub fn dn_expand(
+        msg: *const c_uchar,
+        eomorig: *const c_uchar,
+        comp_dn: *const c_uchar,
+        exp_dn: *mut c_char,
+        length: c_int,
+    ) -> c_int;
+    pub fn dn_skipname(comp_dn: *const c_uchar, eom: *const c_uchar) -> c_int;
 }
 
 cfg_if! {
@@ -57,6 +57,13 @@ s! {
         pub dqb_valid: u32,
     }
 
+    pub struct dqinfo {
+        pub dqi_bgrace: u64,
+        pub dqi_igrace: u64,
+        pub dqi_flags: u32,
+        pub dqi_valid: u32,
+    }
+
     pub struct signalfd_siginfo {
         pub ssi_signo: u32,
         pub ssi_errno: i32,
@@ -357,6 +364,24 @@ s! {
         pub resolve: crate::__u64,
     }
 
+    // linux/prctl.h
+    pub struct prctl_mm_map {
+        pub start_code: crate::__u64,
+        pub end_code: crate::__u64,
+        pub start_data: crate::__u64,
+        pub end_data: crate::__u64,
+        pub start_brk: crate::__u64,
+        pub brk: crate::__u64,
+        pub start_stack: crate::__u64,
+        pub arg_start: crate::__u64,
+        pub arg_end: crate::__u64,
+        pub env_start: crate::__u64,
+        pub env_end: crate::__u64,
+        pub auxv: *mut crate::__u64,
+        pub auxv_size: crate::__u32,
+        pub exe_fd: crate::__u32,
+    }
+
     // linux/ptp_clock.h
     pub struct ptp_clock_time {
         pub sec: crate::__s64,
@@ -1010,6 +1035,77 @@ s! {
         pub n_addrtype: c_int,
         pub n_net: u32,
     }
+
+    // linux/connector.h
+    pub struct cb_id {
+        pub idx: __u32,
+        pub val: __u32,
+    }
+
+    pub struct cn_msg {
+        pub id: cb_id,
+        pub seq: __u32,
+        pub ack: __u32,
+        pub len: __u16,
+        pub flags: __u16,
+        pub data: [__u8; 0],
+    }
+
+    // linux/cn_proc.h
+    pub struct __c_anonymous_proc_event_ack {
+        pub err: __u32,
+    }
+
+    pub struct fork_proc_event {
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+        pub child_pid: crate::pid_t,
+        pub child_tgid: crate::pid_t,
+    }
+
+    pub struct exec_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+    }
+
+    pub struct sid_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+    }
+
+    pub struct ptrace_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub tracer_pid: crate::pid_t,
+        pub tracer_tgid: crate::pid_t,
+    }
+
+    pub struct comm_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub comm: [c_char; 16],
+    }
+
+    pub struct coredump_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+    }
+
+    pub struct exit_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub exit_code: __u32,
+        pub exit_signal: __u32,
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+    }
+
+    pub struct proc_input {
+        pub mcast_op: proc_cn_mcast_op,
+        pub event_type: proc_cn_event,
+    }
 }
 
 cfg_if! {
@@ -1037,6 +1133,18 @@ cfg_if! {
     }
 }
 
+cfg_if! {
+    if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
+        s! {
+            // asm/hwprobe.h
+            pub struct riscv_hwprobe {
+                pub key: crate::__s64,
+                pub value: __u64,
+            }
+        }
+    }
+}
+
 s_no_extra_traits! {
     pub struct af_alg_iv {
         pub ivlen: u32,
@@ -1115,6 +1223,44 @@ s_no_extra_traits! {
         pub request: xsk_tx_metadata_request,
         pub completion: xsk_tx_metadata_completion,
     }
+
+    // linux/cn_proc.h
+    pub union __c_anonymous_id_proc_event_r {
+        pub ruid: __u32,
+        pub rgid: __u32,
+    }
+
+    pub union __c_anonymous_id_proc_event_e {
+        pub euid: __u32,
+        pub egid: __u32,
+    }
+
+    pub struct id_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub r: __c_anonymous_id_proc_event_r,
+        pub e: __c_anonymous_id_proc_event_e,
+    }
+
+    pub union __c_anonymous_proc_event_event_data {
+        pub ack: __c_anonymous_proc_event_ack,
+        pub fork: fork_proc_event,
+        pub exec: exec_proc_event,
+        pub id: id_proc_event,
+        pub sid: sid_proc_event,
+        pub ptrace: ptrace_proc_event,
+        pub comm: comm_proc_event,
+        pub coredump: coredump_proc_event,
+        pub exit: exit_proc_event,
+    }
+
+    #[repr(align(8))]
+    pub struct proc_event {
+        pub what: proc_cn_event,
+        pub cpu: __u32,
+        pub timestamp_ns: crate::__u64,
+        pub event_data: __c_anonymous_proc_event_event_data,
+    }
 }
 
 pub const POSIX_SPAWN_USEVFORK: c_short = 64;
@@ -1128,6 +1274,120 @@ pub const IFF_LOWER_UP: c_int = 0x10000;
 pub const IFF_DORMANT: c_int = 0x20000;
 pub const IFF_ECHO: c_int = 0x40000;
 
+// asm/hwprobe.h
+cfg_if! {
+    if #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))] {
+        pub const RISCV_HWPROBE_KEY_MVENDORID: crate::__s64 = 0;
+        pub const RISCV_HWPROBE_KEY_MARCHID: crate::__s64 = 1;
+        pub const RISCV_HWPROBE_KEY_MIMPID: crate::__s64 = 2;
+        pub const RISCV_HWPROBE_KEY_BASE_BEHAVIOR: crate::__s64 = 3;
+        pub const RISCV_HWPROBE_BASE_BEHAVIOR_IMA: __u64 = 1 << 0;
+        pub const RISCV_HWPROBE_KEY_IMA_EXT_0: crate::__s64 = 4;
+        pub const RISCV_HWPROBE_IMA_FD: __u64 = 1 << 0;
+        pub const RISCV_HWPROBE_IMA_C: __u64 = 1 << 1;
+        pub const RISCV_HWPROBE_IMA_V: __u64 = 1 << 2;
+        pub const RISCV_HWPROBE_EXT_ZBA: __u64 = 1 << 3;
+        pub const RISCV_HWPROBE_EXT_ZBB: __u64 = 1 << 4;
+        pub const RISCV_HWPROBE_EXT_ZBS: __u64 = 1 << 5;
+        pub const RISCV_HWPROBE_EXT_ZICBOZ: __u64 = 1 << 6;
+        pub const RISCV_HWPROBE_EXT_ZBC: __u64 = 1 << 7;
+        pub const RISCV_HWPROBE_EXT_ZBKB: __u64 = 1 << 8;
+        pub const RISCV_HWPROBE_EXT_ZBKC: __u64 = 1 << 9;
+        pub const RISCV_HWPROBE_EXT_ZBKX: __u64 = 1 << 10;
+        pub const RISCV_HWPROBE_EXT_ZKND: __u64 = 1 << 11;
+        pub const RISCV_HWPROBE_EXT_ZKNE: __u64 = 1 << 12;
+        pub const RISCV_HWPROBE_EXT_ZKNH: __u64 = 1 << 13;
+        pub const RISCV_HWPROBE_EXT_ZKSED: __u64 = 1 << 14;
+        pub const RISCV_HWPROBE_EXT_ZKSH: __u64 = 1 << 15;
+        pub const RISCV_HWPROBE_EXT_ZKT: __u64 = 1 << 16;
+        pub const RISCV_HWPROBE_EXT_ZVBB: __u64 = 1 << 17;
+        pub const RISCV_HWPROBE_EXT_ZVBC: __u64 = 1 << 18;
+        pub const RISCV_HWPROBE_EXT_ZVKB: __u64 = 1 << 19;
+        pub const RISCV_HWPROBE_EXT_ZVKG: __u64 = 1 << 20;
+        pub const RISCV_HWPROBE_EXT_ZVKNED: __u64 = 1 << 21;
+        pub const RISCV_HWPROBE_EXT_ZVKNHA: __u64 = 1 << 22;
+        pub const RISCV_HWPROBE_EXT_ZVKNHB: __u64 = 1 << 23;
+        pub const RISCV_HWPROBE_EXT_ZVKSED: __u64 = 1 << 24;
+        pub const RISCV_HWPROBE_EXT_ZVKSH: __u64 = 1 << 25;
+        pub const RISCV_HWPROBE_EXT_ZVKT: __u64 = 1 << 26;
+        pub const RISCV_HWPROBE_EXT_ZFH: __u64 = 1 << 27;
+        pub const RISCV_HWPROBE_EXT_ZFHMIN: __u64 = 1 << 28;
+        pub const RISCV_HWPROBE_EXT_ZIHINTNTL: __u64 = 1 << 29;
+        pub const RISCV_HWPROBE_EXT_ZVFH: __u64 = 1 << 30;
+        pub const RISCV_HWPROBE_EXT_ZVFHMIN: __u64 = 1 << 31;
+        pub const RISCV_HWPROBE_EXT_ZFA: __u64 = 1 << 32;
+        pub const RISCV_HWPROBE_EXT_ZTSO: __u64 = 1 << 33;
+        pub const RISCV_HWPROBE_EXT_ZACAS: __u64 = 1 << 34;
+        pub const RISCV_HWPROBE_EXT_ZICOND: __u64 = 1 << 35;
+        pub const RISCV_HWPROBE_EXT_ZIHINTPAUSE: __u64 = 1 << 36;
+        pub const RISCV_HWPROBE_EXT_ZVE32X: __u64 = 1 << 37;
+        pub const RISCV_HWPROBE_EXT_ZVE32F: __u64 = 1 << 38;
+        pub const RISCV_HWPROBE_EXT_ZVE64X: __u64 = 1 << 39;
+        pub const RISCV_HWPROBE_EXT_ZVE64F: __u64 = 1 << 40;
+        pub const RISCV_HWPROBE_EXT_ZVE64D: __u64 = 1 << 41;
+        pub const RISCV_HWPROBE_EXT_ZIMOP: __u64 = 1 << 42;
+        pub const RISCV_HWPROBE_EXT_ZCA: __u64 = 1 << 43;
+        pub const RISCV_HWPROBE_EXT_ZCB: __u64 = 1 << 44;
+        pub const RISCV_HWPROBE_EXT_ZCD: __u64 = 1 << 45;
+        pub const RISCV_HWPROBE_EXT_ZCF: __u64 = 1 << 46;
+        pub const RISCV_HWPROBE_EXT_ZCMOP: __u64 = 1 << 47;
+        pub const RISCV_HWPROBE_EXT_ZAWRS: __u64 = 1 << 48;
+        pub const RISCV_HWPROBE_KEY_CPUPERF_0: crate::__s64 = 5;
+        pub const RISCV_HWPROBE_MISALIGNED_UNKNOWN: __u64 = 0;
+        pub const RISCV_HWPROBE_MISALIGNED_EMULATED: __u64 = 1;
+        pub const RISCV_HWPROBE_MISALIGNED_SLOW: __u64 = 2;
+        pub const RISCV_HWPROBE_MISALIGNED_FAST: __u64 = 3;
+        pub const RISCV_HWPROBE_MISALIGNED_UNSUPPORTED: __u64 = 4;
+        pub const RISCV_HWPROBE_MISALIGNED_MASK: __u64 = 7;
+        pub const RISCV_HWPROBE_KEY_ZICBOZ_BLOCK_SIZE: crate::__s64 = 6;
+        pub const RISCV_HWPROBE_KEY_HIGHEST_VIRT_ADDRESS: crate::__s64 = 7;
+        pub const RISCV_HWPROBE_KEY_TIME_CSR_FREQ: crate::__s64 = 8;
+        pub const RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF: crate::__s64 = 9;
+        pub const RISCV_HWPROBE_MISALIGNED_SCALAR_UNKNOWN: __u64 = 0;
+        pub const RISCV_HWPROBE_MISALIGNED_SCALAR_EMULATED: __u64 = 1;
+        pub const RISCV_HWPROBE_MISALIGNED_SCALAR_SLOW: __u64 = 2;
+        pub const RISCV_HWPROBE_MISALIGNED_SCALAR_FAST: __u64 = 3;
+        pub const RISCV_HWPROBE_MISALIGNED_SCALAR_UNSUPPORTED: __u64 = 4;
+        pub const RISCV_HWPROBE_KEY_MISALIGNED_VECTOR_PERF: crate::__s64 = 10;
+        pub const RISCV_HWPROBE_MISALIGNED_VECTOR_UNKNOWN: __u64 = 0;
+        pub const RISCV_HWPROBE_MISALIGNED_VECTOR_SLOW: __u64 = 2;
+        pub const RISCV_HWPROBE_MISALIGNED_VECTOR_FAST: __u64 = 3;
+        pub const RISCV_HWPROBE_MISALIGNED_VECTOR_UNSUPPORTED: __u64 = 4;
+
+        pub const RISCV_HWPROBE_WHICH_CPUS: c_uint = 1 << 0;
+    }
+}
+
+// fmtmsg.h
+cfg_if! {
+    if #[cfg(not(target_env = "uclibc"))] {
+        pub const MM_HARD: c_long = 0x001;
+        pub const MM_SOFT: c_long = 0x002;
+        pub const MM_FIRM: c_long = 0x004;
+        pub const MM_APPL: c_long = 0x008;
+        pub const MM_UTIL: c_long = 0x010;
+        pub const MM_OPSYS: c_long = 0x020;
+        pub const MM_RECOVER: c_long = 0x040;
+        pub const MM_NRECOV: c_long = 0x080;
+        pub const MM_PRINT: c_long = 0x100;
+        pub const MM_CONSOLE: c_long = 0x200;
+
+        pub const MM_NOSEV: c_int = 0;
+        pub const MM_HALT: c_int = 1;
+        pub const MM_ERROR: c_int = 2;
+        pub const MM_WARNING: c_int = 3;
+        pub const MM_INFO: c_int = 4;
+
+        pub const MM_NULLSEV: c_int = 0;
+        pub const MM_NULLMC: c_long = 0;
+
+        pub const MM_NOTOK: c_int = -1;
+        pub const MM_OK: c_int = 0;
+        pub const MM_NOMSG: c_int = 1;
+        pub const MM_NOCON: c_int = 4;
+    }
+}
+
 // linux/fcntl.h
 pub const AT_EXECVE_CHECK: c_int = 0x10000;
 
@@ -1239,12 +1499,31 @@ pub const NS_MNT_GET_INFO: Ioctl = _IOR::<mnt_ns_info>(NSIO, 10);
 pub const NS_MNT_GET_NEXT: Ioctl = _IOR::<mnt_ns_info>(NSIO, 11);
 pub const NS_MNT_GET_PREV: Ioctl = _IOR::<mnt_ns_info>(NSIO, 12);
 
+pub const PR_SVE_SET_VL: c_int = 50;
+pub const PR_SVE_SET_VL_ONEXEC: c_int = 1 << 18;
+pub const PR_SVE_GET_VL: c_int = 51;
+pub const PR_SVE_VL_LEN_MASK: c_int = 0xffff;
+pub const PR_SVE_VL_INHERIT: c_int = 1 << 17;
+pub const PR_SME_SET_VL: c_int = 63;
+pub const PR_SME_SET_VL_ONEXEC: c_int = 1 << 18;
+pub const PR_SME_GET_VL: c_int = 64;
+pub const PR_SME_VL_LEN_MASK: c_int = 0xffff;
+pub const PR_SME_VL_INHERIT: c_int = 1 << 17;
 pub const PR_SET_MDWE: c_int = 65;
 pub const PR_GET_MDWE: c_int = 66;
 pub const PR_MDWE_REFUSE_EXEC_GAIN: c_uint = 1 << 0;
 pub const PR_MDWE_NO_INHERIT: c_uint = 1 << 1;
 pub const PR_SET_MEMORY_MERGE: c_int = 67;
 pub const PR_GET_MEMORY_MERGE: c_int = 68;
+pub const PR_RISCV_V_SET_CONTROL: c_int = 69;
+pub const PR_RISCV_V_GET_CONTROL: c_int = 70;
+pub const PR_RISCV_V_VSTATE_CTRL_DEFAULT: c_int = 0;
+pub const PR_RISCV_V_VSTATE_CTRL_OFF: c_int = 1;
+pub const PR_RISCV_V_VSTATE_CTRL_ON: c_int = 2;
+pub const PR_RISCV_V_VSTATE_CTRL_INHERIT: c_int = 1 << 4;
+pub const PR_RISCV_V_VSTATE_CTRL_CUR_MASK: c_int = 0x3;
+pub const PR_RISCV_V_VSTATE_CTRL_NEXT_MASK: c_int = 0xc;
+pub const PR_RISCV_V_VSTATE_CTRL_MASK: c_int = 0x1f;
 
 pub const GRND_NONBLOCK: c_uint = 0x0001;
 pub const GRND_RANDOM: c_uint = 0x0002;
@@ -2378,6 +2657,17 @@ c_enum! {
     }
 }
 
+pub const PROC_EVENT_ALL: proc_cn_event = PROC_EVENT_FORK
+    | PROC_EVENT_EXEC
+    | PROC_EVENT_UID
+    | PROC_EVENT_GID
+    | PROC_EVENT_SID
+    | PROC_EVENT_PTRACE
+    | PROC_EVENT_COMM
+    | PROC_EVENT_NONZERO_EXIT
+    | PROC_EVENT_COREDUMP
+    | PROC_EVENT_EXIT;
+
 // linux/connector.h
 pub const CN_IDX_PROC: c_uint = 0x1;
 pub const CN_VAL_PROC: c_uint = 0x1;
@@ -2398,6 +2688,8 @@ pub const CN_KVP_IDX: c_uint = 0x9;
 pub const CN_KVP_VAL: c_uint = 0x1;
 pub const CN_VSS_IDX: c_uint = 0xA;
 pub const CN_VSS_VAL: c_uint = 0x1;
+pub const CN_NETLINK_USERS: c_uint = 11;
+pub const CONNECTOR_MAX_MSG_SIZE: c_uint = 16384;
 
 // linux/module.h
 pub const MODULE_INIT_IGNORE_MODVERSIONS: c_uint = 0x0001;
@@ -3450,9 +3742,17 @@ extern "C" {
     pub fn seed48(xseed: *mut c_ushort) -> *mut c_ushort;
     pub fn lcong48(p: *mut c_ushort);
 
+    #[cfg(not(target_env = "uclibc"))]
+    pub fn quick_exit(status: c_int) -> !;
+    #[cfg(not(target_env = "uclibc"))]
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
+
     #[cfg_attr(gnu_time_bits64, link_name = "__lutimes64")]
     #[cfg_attr(musl_redir_time64, link_name = "__lutimes_time64")]
     pub fn lutimes(file: *const c_char, times: *const crate::timeval) -> c_int;
+    #[cfg_attr(gnu_time_bits64, link_name = "__futimesat64")]
+    #[cfg_attr(musl_redir_time64, link_name = "__futimesat_time64")]
+    pub fn futimesat(dirfd: c_int, path: *const c_char, times: *const crate::timeval) -> c_int;
 
     pub fn shm_open(name: *const c_char, oflag: c_int, mode: mode_t) -> c_int;
     pub fn shm_unlink(name: *const c_char) -> c_int;
@@ -3565,7 +3865,6 @@ extern "C" {
     pub fn setfsgid(gid: crate::gid_t) -> c_int;
     pub fn setfsuid(uid: crate::uid_t) -> c_int;
 
-    // Not available now on Android
     pub fn mkfifoat(dirfd: c_int, pathname: *const c_char, mode: mode_t) -> c_int;
     pub fn sync_file_range(fd: c_int, offset: off64_t, nbytes: off64_t, flags: c_uint) -> c_int;
 
@@ -3763,6 +4062,34 @@ extern "C" {
     pub fn endnetent();
 }
 
+cfg_if! {
+    if #[cfg(not(target_env = "uclibc"))] {
+        extern "C" {
+            pub static mut getdate_err: c_int;
+
+            pub fn fmtmsg(
+                classification: c_long,
+                label: *const c_char,
+                severity: c_int,
+                text: *const c_char,
+                action: *const c_char,
+                tag: *const c_char,
+            ) -> c_int;
+            pub fn getdate(string: *const c_char) -> *mut crate::tm;
+            pub fn a64l(s: *const c_char) -> c_long;
+            pub fn l64a(value: c_long) -> *mut c_char;
+            pub fn strfmon(s: *mut c_char, max: size_t, format: *const c_char, ...) -> ssize_t;
+            pub fn strfmon_l(
+                s: *mut c_char,
+                max: size_t,
+                locale: crate::locale_t,
+                format: *const c_char,
+                ...
+            ) -> ssize_t;
+        }
+    }
+}
+
 // LFS64 extensions
 //
 // * musl has 64-bit versions only so aliases the LFS64 symbols to the standard ones
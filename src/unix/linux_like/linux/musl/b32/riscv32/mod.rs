@@ -574,6 +574,8 @@ pub const SYS_preadv: c_long = 69;
 pub const SYS_pwritev: c_long = 70;
 pub const SYS_rt_tgsigqueueinfo: c_long = 240;
 pub const SYS_perf_event_open: c_long = 241;
+pub const SYS_riscv_hwprobe: c_long = 258;
+pub const SYS_riscv_flush_icache: c_long = 259;
 pub const SYS_recvmmsg_time64: c_long = 417;
 pub const SYS_fanotify_init: c_long = 262;
 pub const SYS_fanotify_mark: c_long = 263;
@@ -626,6 +628,14 @@ pub const SYS_faccessat2: c_long = 439;
 pub const SYS_process_madvise: c_long = 440;
 pub const SYS_epoll_pwait2: c_long = 441;
 pub const SYS_mount_setattr: c_long = 442;
+pub const SYS_quotactl_fd: c_long = 443;
+pub const SYS_landlock_create_ruleset: c_long = 444;
+pub const SYS_landlock_add_rule: c_long = 445;
+pub const SYS_landlock_restrict_self: c_long = 446;
+pub const SYS_memfd_secret: c_long = 447;
+pub const SYS_process_mrelease: c_long = 448;
+pub const SYS_futex_waitv: c_long = 449;
+pub const SYS_set_mempolicy_home_node: c_long = 450;
 
 // Plain syscalls aliased to their time64 variants
 pub const SYS_clock_gettime: c_long = SYS_clock_gettime64;
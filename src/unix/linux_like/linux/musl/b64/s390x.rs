@@ -735,6 +735,7 @@ pub const SYS_faccessat2: c_long = 439;
 pub const SYS_process_madvise: c_long = 440;
 pub const SYS_epoll_pwait2: c_long = 441;
 pub const SYS_mount_setattr: c_long = 442;
+pub const SYS_quotactl_fd: c_long = 443;
 pub const SYS_landlock_create_ruleset: c_long = 444;
 pub const SYS_landlock_add_rule: c_long = 445;
 pub const SYS_landlock_restrict_self: c_long = 446;
@@ -89,6 +89,21 @@ pub unsafe extern "C" fn getrlimit64(resource: c_int, rlim: *mut crate::rlimit64
     crate::getrlimit(resource, rlim.cast())
 }
 
+#[inline]
+pub unsafe extern "C" fn glob64(
+    pattern: *const c_char,
+    flags: c_int,
+    errfunc: Option<extern "C" fn(epath: *const c_char, errno: c_int) -> c_int>,
+    pglob: *mut crate::glob64_t,
+) -> c_int {
+    crate::glob(pattern, flags, errfunc, pglob)
+}
+
+#[inline]
+pub unsafe extern "C" fn globfree64(pglob: *mut crate::glob64_t) {
+    crate::globfree(pglob)
+}
+
 #[inline]
 pub unsafe extern "C" fn lseek64(fd: c_int, offset: off64_t, whence: c_int) -> off64_t {
     crate::lseek(fd, offset, whence)
@@ -157,6 +172,17 @@ pub unsafe extern "C" fn preadv64(
     crate::preadv(fd, iov, iovcnt, offset)
 }
 
+#[inline]
+pub unsafe extern "C" fn preadv64v2(
+    fd: c_int,
+    iov: *const crate::iovec,
+    iovcnt: c_int,
+    offset: off64_t,
+    flags: c_int,
+) -> ssize_t {
+    crate::preadv2(fd, iov, iovcnt, offset, flags)
+}
+
 #[inline]
 pub unsafe extern "C" fn prlimit64(
     pid: crate::pid_t,
@@ -187,6 +213,17 @@ pub unsafe extern "C" fn pwritev64(
     crate::pwritev(fd, iov, iovcnt, offset)
 }
 
+#[inline]
+pub unsafe extern "C" fn pwritev64v2(
+    fd: c_int,
+    iov: *const crate::iovec,
+    iovcnt: c_int,
+    offset: off64_t,
+    flags: c_int,
+) -> ssize_t {
+    crate::pwritev2(fd, iov, iovcnt, offset, flags)
+}
+
 #[inline]
 pub unsafe extern "C" fn readdir64(dirp: *mut crate::DIR) -> *mut crate::dirent64 {
     crate::readdir(dirp).cast()
@@ -40,6 +40,7 @@ pub type fsblkcnt_t = c_ulonglong;
 pub type fsblkcnt64_t = c_ulonglong;
 pub type fsfilcnt_t = c_ulonglong;
 pub type fsfilcnt64_t = c_ulonglong;
+pub type glob64_t = crate::glob_t;
 pub type rlim_t = c_ulonglong;
 
 cfg_if! {
@@ -574,6 +575,7 @@ pub const O_EXEC: c_int = 0o10000000;
 pub const O_SEARCH: c_int = 0o10000000;
 pub const O_ACCMODE: c_int = 0o10000003;
 pub const O_NDELAY: c_int = O_NONBLOCK;
+pub const ST_RELATIME: c_ulong = 4096;
 pub const NI_MAXHOST: crate::socklen_t = 255;
 pub const PTHREAD_STACK_MIN: size_t = 2048;
 
@@ -754,6 +756,12 @@ cfg_if! {
     }
 }
 
+safe_f! {
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        (fac << 3) | pri
+    }
+}
+
 extern "C" {
     pub fn getrlimit(resource: c_int, rlim: *mut crate::rlimit) -> c_int;
     pub fn setrlimit(resource: c_int, rlim: *const crate::rlimit) -> c_int;
@@ -884,6 +892,62 @@ extern "C" {
         retval: *mut *mut c_void,
         abstime: *const crate::timespec,
     ) -> c_int;
+
+    // resolv.h
+    pub fn res_query(
+        dname: *const c_char,
+        class: c_int,
+        type_: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn res_search(
+        dname: *const c_char,
+        class: c_int,
+        type_: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn res_querydomain(
+        name: *const c_char,
+        domain: *const c_char,
+        class: c_int,
+        type_: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn res_mkquery(
+        op: c_int,
+        dname: *const c_char,
+        class: c_int,
+        type_: c_int,
+        data: *const c_uchar,
+        datalen: c_int,
+        newrr: *const c_uchar,
+        buf: *mut c_uchar,
+        buflen: c_int,
+    ) -> c_int;
+    pub fn res_send(
+        msg: *const c_uchar,
+        msglen: c_int,
+        answer: *mut c_uchar,
+        anslen: c_int,
+    ) -> c_int;
+    pub fn dn_comp(
+        src: *const c_char,
+        dst: *mut c_uchar,
+        space: c_int,
+        dnptrs: *mut *mut c_uchar,
+        lastdnptr: *mut *mut c_uchar,
+    ) -> c_int;
+    pub fn dn_expand(
+        msg: *const c_uchar,
+        eomorig: *const c_uchar,
+        comp_dn: *const c_uchar,
+        exp_dn: *mut c_char,
+        length: c_int,
+    ) -> c_int;
+    pub fn dn_skipname(comp_dn: *const c_uchar, eom: *const c_uchar) -> c_int;
 }
 
 // Alias <foo> to <foo>64 to mimic glibc's LFS64 support
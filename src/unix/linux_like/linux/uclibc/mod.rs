@@ -391,6 +391,22 @@ extern "C" {
 
     pub fn pwritev(fd: c_int, iov: *const crate::iovec, iovcnt: c_int, offset: off64_t) -> ssize_t;
     pub fn preadv(fd: c_int, iov: *const crate::iovec, iovcnt: c_int, offset: off64_t) -> ssize_t;
+    pub fn process_vm_readv(
+        pid: crate::pid_t,
+        local_iov: *const crate::iovec,
+        liovcnt: c_ulong,
+        remote_iov: *const crate::iovec,
+        riovcnt: c_ulong,
+        flags: c_ulong,
+    ) -> ssize_t;
+    pub fn process_vm_writev(
+        pid: crate::pid_t,
+        local_iov: *const crate::iovec,
+        liovcnt: c_ulong,
+        remote_iov: *const crate::iovec,
+        riovcnt: c_ulong,
+        flags: c_ulong,
+    ) -> ssize_t;
 
     pub fn sethostid(hostid: c_long) -> c_int;
     pub fn fanotify_mark(
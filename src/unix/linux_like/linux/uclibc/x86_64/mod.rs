@@ -312,9 +312,16 @@ pub const O_CLOEXEC: c_int = 0x80000;
 pub const O_CREAT: c_int = 0100;
 pub const O_DIRECTORY: c_int = 0o200000;
 pub const O_EXCL: c_int = 0o200;
+pub const O_NOCTTY: c_int = 0o400;
 pub const O_NOFOLLOW: c_int = 0x20000;
 pub const O_NONBLOCK: c_int = 0o4000;
 pub const O_TRUNC: c_int = 0o1000;
+pub const MAP_HUGETLB: c_int = 0x040000;
+pub const EFD_CLOEXEC: c_int = 0x80000;
+pub const EFD_NONBLOCK: c_int = 0x800;
+pub const EPOLL_CLOEXEC: c_int = 0x80000;
+pub const SFD_CLOEXEC: c_int = 0x80000;
+pub const SFD_NONBLOCK: c_int = 0x800;
 pub const NCCS: usize = 32;
 pub const SIG_SETMASK: c_int = 2; // Set the set of blocked signals
 pub const __SIZEOF_PTHREAD_MUTEX_T: usize = 40;
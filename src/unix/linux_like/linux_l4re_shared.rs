@@ -1710,6 +1710,11 @@ extern "C" {
     pub fn jrand48(xseed: *mut c_ushort) -> c_long;
     pub fn srand48(seed: c_long);
 
+    pub fn random() -> c_long;
+    pub fn srandom(seed: c_uint);
+    pub fn initstate(seed: c_uint, state: *mut c_char, size: size_t) -> *mut c_char;
+    pub fn setstate(state: *mut c_char) -> *mut c_char;
+
     pub fn setpwent();
     pub fn endpwent();
     pub fn getpwent() -> *mut passwd;
@@ -504,6 +504,9 @@ pub const S_IRWXO: mode_t = 0o0007;
 pub const S_IXOTH: mode_t = 0o0001;
 pub const S_IWOTH: mode_t = 0o0002;
 pub const S_IROTH: mode_t = 0o0004;
+pub const ACCESSPERMS: mode_t = 0o0777;
+pub const ALLPERMS: mode_t = 0o7777;
+pub const DEFFILEMODE: mode_t = 0o0666;
 pub const F_OK: c_int = 0;
 pub const R_OK: c_int = 4;
 pub const W_OK: c_int = 2;
@@ -660,6 +663,7 @@ cfg_if! {
         pub const MADV_POPULATE_READ: c_int = 22;
         pub const MADV_POPULATE_WRITE: c_int = 23;
         pub const MADV_DONTNEED_LOCKED: c_int = 24;
+        pub const MADV_COLLAPSE: c_int = 25;
     }
 }
 
@@ -1106,6 +1110,10 @@ cfg_if! {
         pub const QIF_USAGE: u32 = 10;
         pub const QIF_TIMES: u32 = 48;
         pub const QIF_ALL: u32 = 63;
+        pub const IIF_BGRACE: u32 = 1;
+        pub const IIF_IGRACE: u32 = 2;
+        pub const IIF_FLAGS: u32 = 4;
+        pub const IIF_ALL: u32 = 7;
 
         pub const Q_SYNC: c_int = 0x800001;
         pub const Q_QUOTAON: c_int = 0x800002;
@@ -1549,6 +1557,45 @@ cfg_if! {
 
         pub const FICLONE: Ioctl = _IOW::<c_int>(0x94, 9);
         pub const FICLONERANGE: Ioctl = _IOW::<crate::file_clone_range>(0x94, 13);
+        pub const FIFREEZE: Ioctl = _IOWR::<c_int>(b'X' as u32, 119);
+        pub const FITHAW: Ioctl = _IOWR::<c_int>(b'X' as u32, 120);
+
+        // xfs/xfs_fs.h
+        pub const XFS_IOC_FREEZE: Ioctl = _IOWR::<c_int>(b'X' as u32, 119);
+        pub const XFS_IOC_THAW: Ioctl = _IOWR::<c_int>(b'X' as u32, 120);
+
+        // Inode flags for FS_IOC_GETFLAGS / FS_IOC_SETFLAGS
+        pub const FS_SECRM_FL: c_int = 0x00000001;
+        pub const FS_UNRM_FL: c_int = 0x00000002;
+        pub const FS_COMPR_FL: c_int = 0x00000004;
+        pub const FS_SYNC_FL: c_int = 0x00000008;
+        pub const FS_IMMUTABLE_FL: c_int = 0x00000010;
+        pub const FS_APPEND_FL: c_int = 0x00000020;
+        pub const FS_NODUMP_FL: c_int = 0x00000040;
+        pub const FS_NOATIME_FL: c_int = 0x00000080;
+        pub const FS_DIRTY_FL: c_int = 0x00000100;
+        pub const FS_COMPRBLK_FL: c_int = 0x00000200;
+        pub const FS_NOCOMP_FL: c_int = 0x00000400;
+        pub const FS_ENCRYPT_FL: c_int = 0x00000800;
+        pub const FS_BTREE_FL: c_int = 0x00001000;
+        pub const FS_INDEX_FL: c_int = 0x00001000;
+        pub const FS_IMAGIC_FL: c_int = 0x00002000;
+        pub const FS_JOURNAL_DATA_FL: c_int = 0x00004000;
+        pub const FS_NOTAIL_FL: c_int = 0x00008000;
+        pub const FS_DIRSYNC_FL: c_int = 0x00010000;
+        pub const FS_TOPDIR_FL: c_int = 0x00020000;
+        pub const FS_HUGE_FILE_FL: c_int = 0x00040000;
+        pub const FS_EXTENT_FL: c_int = 0x00080000;
+        pub const FS_VERITY_FL: c_int = 0x00100000;
+        pub const FS_EA_INODE_FL: c_int = 0x00200000;
+        pub const FS_EOFBLOCKS_FL: c_int = 0x00400000;
+        pub const FS_NOCOW_FL: c_int = 0x00800000;
+        pub const FS_DAX_FL: c_int = 0x02000000;
+        pub const FS_INLINE_DATA_FL: c_int = 0x10000000;
+        pub const FS_PROJINHERIT_FL: c_int = 0x20000000;
+        pub const FS_CASEFOLD_FL: c_int = 0x40000000;
+        pub const FS_FL_USER_VISIBLE: c_int = 0x0003DFFF;
+        pub const FS_FL_USER_MODIFIABLE: c_int = 0x000380FF;
     }
 }
 
@@ -1607,6 +1654,7 @@ cfg_if! {
         pub const TRACEFS_MAGIC: c_long = 0x74726163;
         pub const UDF_SUPER_MAGIC: c_long = 0x15013346;
         pub const USBDEVICE_SUPER_MAGIC: c_long = 0x00009fa2;
+        pub const V9FS_MAGIC: c_long = 0x01021997;
         pub const XENFS_SUPER_MAGIC: c_long = u32_cast_long(0xabba1974);
         pub const NSFS_MAGIC: c_long = 0x6e736673;
     } else if #[cfg(target_arch = "s390x")] {
@@ -1661,6 +1709,7 @@ cfg_if! {
         pub const TRACEFS_MAGIC: c_uint = 0x74726163;
         pub const UDF_SUPER_MAGIC: c_uint = 0x15013346;
         pub const USBDEVICE_SUPER_MAGIC: c_uint = 0x00009fa2;
+        pub const V9FS_MAGIC: c_uint = 0x01021997;
         pub const XENFS_SUPER_MAGIC: c_uint = 0xabba1974;
         pub const NSFS_MAGIC: c_uint = 0x6e736673;
     }
@@ -2020,6 +2069,9 @@ extern "C" {
     pub fn setresgid(rgid: crate::gid_t, egid: crate::gid_t, sgid: crate::gid_t) -> c_int;
     #[cfg(not(target_os = "l4re"))]
     pub fn setresuid(ruid: crate::uid_t, euid: crate::uid_t, suid: crate::uid_t) -> c_int;
+    #[cfg_attr(any(gnu_time_bits64, musl_redir_time64), link_name = "__wait3_time64")]
+    #[cfg(not(any(target_os = "l4re", target_os = "android")))]
+    pub fn wait3(status: *mut c_int, options: c_int, rusage: *mut crate::rusage) -> crate::pid_t;
     #[cfg_attr(any(gnu_time_bits64, musl_redir_time64), link_name = "__wait4_time64")]
     #[cfg(not(target_os = "l4re"))]
     pub fn wait4(
@@ -2058,6 +2110,7 @@ extern "C" {
     pub fn uname(buf: *mut crate::utsname) -> c_int;
 
     pub fn strchrnul(s: *const c_char, c: c_int) -> *mut c_char;
+    pub fn strsep(string: *mut *mut c_char, delim: *const c_char) -> *mut c_char;
 
     pub fn strftime(
         s: *mut c_char,
@@ -2222,6 +2275,7 @@ cfg_if! {
 cfg_if! {
     if #[cfg(any(
         target_env = "gnu",
+        // Only available in API Version 30+ on Android
         target_os = "android",
         all(target_env = "musl", musl_v1_2_3)
     ))] {
@@ -431,6 +431,39 @@ cfg_if! {
     }
 }
 
+f! {
+    /// Sets `*tvp` to zero, like the `<sys/time.h>` `timerclear` macro.
+    pub fn timerclear(tvp: *mut timeval) -> () {
+        (*tvp).tv_sec = 0;
+        (*tvp).tv_usec = 0;
+    }
+
+    /// Returns whether `*tvp` holds a non-zero time, like the `<sys/time.h>` `timerisset` macro.
+    pub fn timerisset(tvp: *const timeval) -> bool {
+        (*tvp).tv_sec != 0 || (*tvp).tv_usec != 0
+    }
+
+    /// Stores `*a + *b` in `*result`, like the `<sys/time.h>` `timeradd` macro.
+    pub fn timeradd(a: *const timeval, b: *const timeval, result: *mut timeval) -> () {
+        (*result).tv_sec = (*a).tv_sec + (*b).tv_sec;
+        (*result).tv_usec = (*a).tv_usec + (*b).tv_usec;
+        if (*result).tv_usec >= 1_000_000 {
+            (*result).tv_sec += 1;
+            (*result).tv_usec -= 1_000_000;
+        }
+    }
+
+    /// Stores `*a - *b` in `*result`, like the `<sys/time.h>` `timersub` macro.
+    pub fn timersub(a: *const timeval, b: *const timeval, result: *mut timeval) -> () {
+        (*result).tv_sec = (*a).tv_sec - (*b).tv_sec;
+        (*result).tv_usec = (*a).tv_usec - (*b).tv_usec;
+        if (*result).tv_usec < 0 {
+            (*result).tv_sec -= 1;
+            (*result).tv_usec += 1_000_000;
+        }
+    }
+}
+
 extern "C" {
     pub static in6addr_loopback: in6_addr;
     pub static in6addr_any: in6_addr;
@@ -738,6 +771,7 @@ extern "C" {
     pub fn abort() -> !;
     pub fn exit(status: c_int) -> !;
     pub fn _exit(status: c_int) -> !;
+    pub fn _Exit(status: c_int) -> !;
     #[cfg_attr(
         all(target_os = "macos", target_arch = "x86"),
         link_name = "system$UNIX2003"
@@ -2135,6 +2169,14 @@ extern "C" {
 }
 
 safe_f! {
+    pub const fn LOG_MASK(pri: c_int) -> c_int {
+        1 << pri
+    }
+
+    pub const fn LOG_UPTO(pri: c_int) -> c_int {
+        (1 << (pri + 1)) - 1
+    }
+
     // It seems htonl, etc are macros on macOS. So we have to reimplement them. So let's
     // reimplement them for all UNIX platforms
     pub const fn htonl(hostlong: u32) -> u32 {
@@ -2151,6 +2193,61 @@ safe_f! {
     }
 }
 
+cfg_if! {
+    if #[cfg(any(
+        target_os = "linux",
+        target_os = "l4re",
+        target_os = "android",
+        target_os = "emscripten",
+        target_os = "hurd",
+        target_os = "freebsd",
+        target_os = "dragonfly",
+        target_os = "openbsd",
+        target_os = "netbsd"
+    ))] {
+        // The `<endian.h>` (`<sys/endian.h>` on the BSDs) conversions are only ever provided as
+        // macros as well.
+        safe_f! {
+            pub const fn htobe16(host_16bits: u16) -> u16 {
+                u16::to_be(host_16bits)
+            }
+            pub const fn htole16(host_16bits: u16) -> u16 {
+                u16::to_le(host_16bits)
+            }
+            pub const fn be16toh(big_endian_16bits: u16) -> u16 {
+                u16::from_be(big_endian_16bits)
+            }
+            pub const fn le16toh(little_endian_16bits: u16) -> u16 {
+                u16::from_le(little_endian_16bits)
+            }
+            pub const fn htobe32(host_32bits: u32) -> u32 {
+                u32::to_be(host_32bits)
+            }
+            pub const fn htole32(host_32bits: u32) -> u32 {
+                u32::to_le(host_32bits)
+            }
+            pub const fn be32toh(big_endian_32bits: u32) -> u32 {
+                u32::from_be(big_endian_32bits)
+            }
+            pub const fn le32toh(little_endian_32bits: u32) -> u32 {
+                u32::from_le(little_endian_32bits)
+            }
+            pub const fn htobe64(host_64bits: u64) -> u64 {
+                u64::to_be(host_64bits)
+            }
+            pub const fn htole64(host_64bits: u64) -> u64 {
+                u64::to_le(host_64bits)
+            }
+            pub const fn be64toh(big_endian_64bits: u64) -> u64 {
+                u64::from_be(big_endian_64bits)
+            }
+            pub const fn le64toh(little_endian_64bits: u64) -> u64 {
+                u64::from_le(little_endian_64bits)
+            }
+        }
+    }
+}
+
 cfg_if! {
     if #[cfg(not(any(
         target_os = "emscripten",
@@ -2360,7 +2457,8 @@ cfg_if! {
     } else if #[cfg(not(any(
         target_os = "solaris",
         target_os = "illumos",
-        target_os = "nto"
+        target_os = "nto",
+        target_os = "haiku"
     )))] {
         extern "C" {
             #[cfg(not(target_os = "l4re"))]
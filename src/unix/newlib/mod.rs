@@ -563,12 +563,18 @@ pub const S_IFIFO: mode_t = 0o1_0000;
 pub const S_IRUSR: mode_t = 0o0400;
 pub const S_IWUSR: mode_t = 0o0200;
 pub const S_IXUSR: mode_t = 0o0100;
+pub const S_IRWXU: mode_t = 0o0700;
 pub const S_IRGRP: mode_t = 0o0040;
 pub const S_IWGRP: mode_t = 0o0020;
 pub const S_IXGRP: mode_t = 0o0010;
+pub const S_IRWXG: mode_t = 0o0070;
 pub const S_IROTH: mode_t = 0o0004;
 pub const S_IWOTH: mode_t = 0o0002;
 pub const S_IXOTH: mode_t = 0o0001;
+pub const S_IRWXO: mode_t = 0o0007;
+pub const ACCESSPERMS: mode_t = 0o0777;
+pub const ALLPERMS: mode_t = 0o7777;
+pub const DEFFILEMODE: mode_t = 0o0666;
 
 pub const SOL_TCP: c_int = 6;
 
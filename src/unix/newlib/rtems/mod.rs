@@ -13,6 +13,7 @@ pub const AF_UNIX: c_int = 1;
 
 pub const RTLD_DEFAULT: *mut c_void = -2isize as *mut c_void;
 
+pub const UTIME_NOW: c_long = -2;
 pub const UTIME_OMIT: c_long = -1;
 pub const AT_FDCWD: c_int = -2;
 
@@ -125,6 +126,12 @@ safe_f! {
 
 extern "C" {
     pub fn futimens(fd: c_int, times: *const crate::timespec) -> c_int;
+    pub fn utimensat(
+        dirfd: c_int,
+        path: *const c_char,
+        times: *const crate::timespec,
+        flag: c_int,
+    ) -> c_int;
     pub fn writev(fd: c_int, iov: *const crate::iovec, iovcnt: c_int) -> ssize_t;
     pub fn readv(fd: c_int, iov: *const crate::iovec, iovcnt: c_int) -> ssize_t;
 
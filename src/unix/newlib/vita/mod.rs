@@ -106,6 +106,7 @@ pub const MSG_DONTWAIT: c_int = 0x80;
 pub const MSG_BCAST: c_int = 0x100;
 pub const MSG_MCAST: c_int = 0x200;
 
+pub const UTIME_NOW: c_long = -2;
 pub const UTIME_OMIT: c_long = -1;
 pub const AT_FDCWD: c_int = -2;
 
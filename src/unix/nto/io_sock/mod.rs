@@ -57,6 +57,7 @@ pub const AF_INET6: c_int = 28;
 pub const AF_BLUETOOTH: c_int = 36;
 pub const pseudo_AF_KEY: c_int = 27;
 pub const MSG_NOSIGNAL: c_int = 0x20000;
+pub const MSG_CMSG_CLOEXEC: c_int = 0x40000;
 pub const MSG_WAITFORONE: c_int = 0x00080000;
 pub const IPPROTO_DIVERT: c_int = 258;
 pub const RTF_BROADCAST: u32 = 0x400000;
@@ -1743,6 +1743,18 @@ pub const NCCS: usize = 40;
 pub const MAP_ANON: c_int = MAP_ANONYMOUS;
 pub const MAP_ANONYMOUS: c_int = 0x00080000;
 
+pub const SHMCTL_ANON: c_int = 0x00000001;
+pub const SHMCTL_PHYS: c_int = 0x00000002;
+pub const SHMCTL_GLOBAL: c_int = 0x00000004;
+pub const SHMCTL_PRIV: c_int = 0x00000008;
+pub const SHMCTL_LOWERPROT: c_int = 0x00000010;
+pub const SHMCTL_LAZYWRITE: c_int = 0x00000020;
+pub const SHMCTL_NOX64K: c_int = 0x00000040;
+
+pub const POSIX_TYPED_MEM_ALLOCATE: c_int = 0x00000001;
+pub const POSIX_TYPED_MEM_ALLOCATE_CONTIG: c_int = 0x00000002;
+pub const POSIX_TYPED_MEM_MAP_ALLOCATABLE: c_int = 0x00000004;
+
 pub const MCL_CURRENT: c_int = 0x000000001;
 pub const MCL_FUTURE: c_int = 0x000000002;
 
@@ -2418,6 +2430,11 @@ extern "C" {
         stackaddr: *mut *mut c_void,
         stacksize: *mut size_t,
     ) -> c_int;
+    pub fn pthread_attr_setstack(
+        attr: *mut crate::pthread_attr_t,
+        stackaddr: *mut c_void,
+        stacksize: size_t,
+    ) -> c_int;
     pub fn memalign(align: size_t, size: size_t) -> *mut c_void;
     pub fn setgroups(ngroups: c_int, ptr: *const crate::gid_t) -> c_int;
 
@@ -2545,6 +2562,34 @@ extern "C" {
     pub fn posix_madvise(addr: *mut c_void, len: size_t, advice: c_int) -> c_int;
 
     pub fn shm_unlink(name: *const c_char) -> c_int;
+    pub fn shm_ctl(fd: c_int, flags: c_int, paddr: u64, size: u64) -> c_int;
+    pub fn shm_ctl_special(
+        fd: c_int,
+        flags: c_int,
+        paddr: u64,
+        size: u64,
+        special: c_uint,
+    ) -> c_int;
+
+    pub fn mmap_device_memory(
+        addr: *mut c_void,
+        len: size_t,
+        prot: c_int,
+        flags: c_int,
+        physical: u64,
+    ) -> *mut c_void;
+    pub fn munmap_device_memory(addr: *mut c_void, len: size_t) -> c_int;
+    pub fn mmap_device_io(len: size_t, io: u64) -> crate::uintptr_t;
+    pub fn munmap_device_io(io: crate::uintptr_t, len: size_t) -> c_int;
+
+    pub fn posix_typed_mem_open(name: *const c_char, oflag: c_int, tflag: c_int) -> c_int;
+    pub fn posix_mem_offset(
+        addr: *const c_void,
+        len: size_t,
+        off: *mut off_t,
+        contig_len: *mut size_t,
+        fildes: *mut c_int,
+    ) -> c_int;
 
     pub fn seekdir(dirp: *mut crate::DIR, loc: c_long);
 
@@ -469,12 +469,15 @@ pub const S_IFCHR: u32 = 0x2000;
 pub const S_IRUSR: u32 = 0x100;
 pub const S_IWUSR: u32 = 0x80;
 pub const S_IXUSR: u32 = 0x40;
+pub const S_IRWXU: u32 = 0x1c0;
 pub const S_IRGRP: u32 = 0x20;
 pub const S_IWGRP: u32 = 0x10;
 pub const S_IXGRP: u32 = 0x8;
+pub const S_IRWXG: u32 = 0x38;
 pub const S_IROTH: u32 = 0x004;
 pub const S_IWOTH: u32 = 0x002;
 pub const S_IXOTH: u32 = 0x001;
+pub const S_IRWXO: u32 = 0x007;
 
 // sys/poll.h
 pub const POLLIN: i16 = 0x01;
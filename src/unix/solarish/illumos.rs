@@ -229,6 +229,9 @@ pub const TFD_TIMER_CANCEL_ON_SET: i32 = 1 << 1;
 extern "C" {
     pub fn eventfd(initval: c_uint, flags: c_int) -> c_int;
 
+    pub fn quick_exit(status: c_int) -> !;
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
+
     pub fn epoll_pwait(
         epfd: c_int,
         events: *mut crate::epoll_event,
@@ -262,11 +265,6 @@ extern "C" {
         attr: *const crate::pthread_attr_t,
         stackaddr: *mut *mut c_void,
     ) -> c_int;
-    pub fn pthread_attr_setstack(
-        attr: *mut crate::pthread_attr_t,
-        stackaddr: *mut c_void,
-        stacksize: size_t,
-    ) -> c_int;
     pub fn pthread_attr_setstackaddr(
         attr: *mut crate::pthread_attr_t,
         stackaddr: *mut c_void,
@@ -530,6 +530,28 @@ s! {
         pub sigev_notify_attributes: *const crate::pthread_attr_t,
         __sigev_pad2: Padding<c_int>,
     }
+
+    pub struct strioctl {
+        pub ic_cmd: c_int,
+        pub ic_timout: c_int,
+        pub ic_len: c_int,
+        pub ic_dp: *mut c_char,
+    }
+
+    pub struct strbuf {
+        pub maxlen: c_int,
+        pub len: c_int,
+        pub buf: crate::caddr_t,
+    }
+
+    pub struct str_mlist {
+        pub l_name: [c_char; FMNAMESZ + 1],
+    }
+
+    pub struct str_list {
+        pub sl_nmods: c_int,
+        pub sl_modlist: *mut str_mlist,
+    }
 }
 
 s_no_extra_traits! {
@@ -903,6 +925,7 @@ cfg_if! {
 
 pub const ST_RDONLY: c_ulong = 1;
 pub const ST_NOSUID: c_ulong = 2;
+pub const ST_NOTRUNC: c_ulong = 4;
 
 pub const NI_MAXHOST: crate::socklen_t = 1025;
 pub const NI_MAXSERV: crate::socklen_t = 32;
@@ -954,6 +977,8 @@ pub const S_IFDIR: mode_t = 0o4_0000;
 pub const S_IFREG: mode_t = 0o10_0000;
 pub const S_IFLNK: mode_t = 0o12_0000;
 pub const S_IFSOCK: mode_t = 0o14_0000;
+pub const S_IFDOOR: mode_t = 0xd000;
+pub const S_IFPORT: mode_t = 0xe000;
 pub const S_IFMT: mode_t = 0o17_0000;
 pub const S_IEXEC: mode_t = 0o0100;
 pub const S_IWRITE: mode_t = 0o0200;
@@ -1770,7 +1795,14 @@ pub const _RWL_MAGIC: u16 = 0x5257; // RW
 
 pub const NCCS: usize = 19;
 
+pub const LOG_ALTCRON: c_int = 9 << 3;
+pub const LOG_AUTHPRIV: c_int = 10 << 3;
+pub const LOG_FTP: c_int = 11 << 3;
+pub const LOG_NTP: c_int = 12 << 3;
+pub const LOG_AUDIT: c_int = 13 << 3;
+pub const LOG_CONSOLE: c_int = 14 << 3;
 pub const LOG_CRON: c_int = 15 << 3;
+pub const LOG_NFACILITIES: c_int = 24;
 
 pub const PTHREAD_MUTEX_INITIALIZER: pthread_mutex_t = pthread_mutex_t {
     __pthread_mutex_flag1: 0,
@@ -2068,6 +2100,44 @@ pub const I_ESETSIG: c_int = STR | 0o45;
 pub const I_EGETSIG: c_int = STR | 0o46;
 pub const __I_PUSH_NOCTTY: c_int = STR | 0o47;
 
+pub const FMNAMESZ: usize = 8;
+
+pub const FLUSHR: c_int = 0x01;
+pub const FLUSHW: c_int = 0x02;
+pub const FLUSHRW: c_int = 0x03;
+pub const FLUSHBAND: c_int = 0x04;
+
+// `I_SETSIG` events
+pub const S_INPUT: c_int = 0x0001;
+pub const S_HIPRI: c_int = 0x0002;
+pub const S_OUTPUT: c_int = 0x0004;
+pub const S_MSG: c_int = 0x0008;
+pub const S_ERROR: c_int = 0x0010;
+pub const S_HANGUP: c_int = 0x0020;
+pub const S_RDNORM: c_int = 0x0040;
+pub const S_WRNORM: c_int = S_OUTPUT;
+pub const S_RDBAND: c_int = 0x0080;
+pub const S_WRBAND: c_int = 0x0100;
+pub const S_BANDURG: c_int = 0x0200;
+
+// `I_SRDOPT` modes
+pub const RNORM: c_int = 0x0;
+pub const RMSGD: c_int = 0x1;
+pub const RMSGN: c_int = 0x2;
+
+// `putmsg`/`getmsg` flags
+pub const RS_HIPRI: c_int = 0x01;
+pub const MSG_HIPRI: c_int = 0x01;
+pub const MSG_ANY: c_int = 0x02;
+pub const MSG_BAND: c_int = 0x04;
+pub const MORECTL: c_int = 1;
+pub const MOREDATA: c_int = 2;
+
+pub const MUXID_ALL: c_int = -1;
+
+pub const ANYMARK: c_int = 0x01;
+pub const LASTMARK: c_int = 0x02;
+
 // 3SOCKET flags
 pub const SOCK_CLOEXEC: c_int = 0x080000;
 pub const SOCK_NONBLOCK: c_int = 0x100000;
@@ -2317,6 +2387,10 @@ safe_f! {
         (status & 0x80) != 0
     }
 
+    pub const fn LOG_MAKEPRI(fac: c_int, pri: c_int) -> c_int {
+        (fac << 3) | pri
+    }
+
     pub const fn MR_GET_TYPE(flags: c_uint) -> c_uint {
         flags & 0x0000ffff
     }
@@ -2337,6 +2411,10 @@ extern "C" {
     pub fn labs(i: c_long) -> c_long;
     pub fn rand() -> c_int;
     pub fn srand(seed: c_uint);
+    pub fn random() -> c_long;
+    pub fn srandom(seed: c_uint);
+    pub fn initstate(seed: c_uint, state: *mut c_char, size: size_t) -> *mut c_char;
+    pub fn setstate(state: *const c_char) -> *mut c_char;
     pub fn getentropy(buf: *mut c_void, buflen: size_t) -> c_int;
     pub fn getrandom(bbuf: *mut c_void, buflen: size_t, flags: c_uint) -> ssize_t;
 
@@ -2355,6 +2433,33 @@ extern "C" {
     pub fn initgroups(name: *const c_char, basegid: crate::gid_t) -> c_int;
     pub fn setgroups(ngroups: c_int, ptr: *const crate::gid_t) -> c_int;
     pub fn ioctl(fildes: c_int, request: c_int, ...) -> c_int;
+    pub fn isastream(fildes: c_int) -> c_int;
+    pub fn getmsg(
+        fildes: c_int,
+        ctlptr: *mut strbuf,
+        dataptr: *mut strbuf,
+        flagsp: *mut c_int,
+    ) -> c_int;
+    pub fn getpmsg(
+        fildes: c_int,
+        ctlptr: *mut strbuf,
+        dataptr: *mut strbuf,
+        bandp: *mut c_int,
+        flagsp: *mut c_int,
+    ) -> c_int;
+    pub fn putmsg(
+        fildes: c_int,
+        ctlptr: *const strbuf,
+        dataptr: *const strbuf,
+        flags: c_int,
+    ) -> c_int;
+    pub fn putpmsg(
+        fildes: c_int,
+        ctlptr: *const strbuf,
+        dataptr: *const strbuf,
+        band: c_int,
+        flags: c_int,
+    ) -> c_int;
     pub fn mprotect(addr: *mut c_void, len: size_t, prot: c_int) -> c_int;
     pub fn ___errno() -> *mut c_int;
     pub fn clock_getres(clk_id: crate::clockid_t, tp: *mut crate::timespec) -> c_int;
@@ -2401,11 +2506,21 @@ extern "C" {
         f: extern "C" fn(*mut c_void) -> *mut c_void,
         value: *mut c_void,
     ) -> c_int;
+    pub fn pthread_attr_getguardsize(
+        attr: *const crate::pthread_attr_t,
+        guardsize: *mut size_t,
+    ) -> c_int;
+    pub fn pthread_attr_setguardsize(attr: *mut crate::pthread_attr_t, guardsize: size_t) -> c_int;
     pub fn pthread_attr_getstack(
         attr: *const crate::pthread_attr_t,
         stackaddr: *mut *mut c_void,
         stacksize: *mut size_t,
     ) -> c_int;
+    pub fn pthread_attr_setstack(
+        attr: *mut crate::pthread_attr_t,
+        stackaddr: *mut c_void,
+        stacksize: size_t,
+    ) -> c_int;
     pub fn pthread_condattr_getclock(
         attr: *const pthread_condattr_t,
         clock_id: *mut clockid_t,
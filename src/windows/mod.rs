@@ -134,6 +134,12 @@ pub const _O_OBTAIN_DIR: c_int = 0x2000;
 pub const O_SEQUENTIAL: c_int = 0x0020;
 pub const O_RANDOM: c_int = 0x0010;
 
+// share.h
+pub const SH_DENYRW: c_int = 0x10;
+pub const SH_DENYWR: c_int = 0x20;
+pub const SH_DENYRD: c_int = 0x30;
+pub const SH_DENYNO: c_int = 0x40;
+
 pub const S_IFCHR: c_ushort = 0o2_0000;
 pub const S_IFDIR: c_ushort = 0o4_0000;
 pub const S_IFREG: c_ushort = 0o10_0000;
@@ -354,7 +360,10 @@ extern "C" {
     pub fn abort() -> !;
     pub fn exit(status: c_int) -> !;
     pub fn _exit(status: c_int) -> !;
+    pub fn _Exit(status: c_int) -> !;
     pub fn atexit(cb: extern "C" fn()) -> c_int;
+    pub fn quick_exit(status: c_int) -> !;
+    pub fn at_quick_exit(cb: extern "C" fn()) -> c_int;
     pub fn system(s: *const c_char) -> c_int;
     pub fn getenv(s: *const c_char) -> *mut c_char;
 
@@ -469,8 +478,28 @@ extern "C" {
     pub fn open(path: *const c_char, oflag: c_int, ...) -> c_int;
     #[link_name = "_wopen"]
     pub fn wopen(path: *const wchar_t, oflag: c_int, ...) -> c_int;
+    #[link_name = "_sopen_s"]
+    pub fn sopen_s(
+        pfh: *mut c_int,
+        filename: *const c_char,
+        oflag: c_int,
+        shflag: c_int,
+        pmode: c_int,
+    ) -> crate::errno_t;
+    #[link_name = "_wsopen_s"]
+    pub fn wsopen_s(
+        pfh: *mut c_int,
+        filename: *const wchar_t,
+        oflag: c_int,
+        shflag: c_int,
+        pmode: c_int,
+    ) -> crate::errno_t;
     #[link_name = "_creat"]
     pub fn creat(path: *const c_char, mode: c_int) -> c_int;
+    #[link_name = "_mktemp_s"]
+    pub fn mktemp_s(template: *mut c_char, size: size_t) -> crate::errno_t;
+    #[link_name = "_wmktemp_s"]
+    pub fn wmktemp_s(template: *mut wchar_t, size: size_t) -> crate::errno_t;
     #[link_name = "_access"]
     pub fn access(path: *const c_char, amode: c_int) -> c_int;
     #[link_name = "_chdir"]
@@ -564,6 +593,8 @@ extern "C" {
     pub fn aligned_free(ptr: *mut c_void);
     #[link_name = "_aligned_realloc"]
     pub fn aligned_realloc(memblock: *mut c_void, size: size_t, alignment: size_t) -> *mut c_void;
+    #[link_name = "_get_heap_handle"]
+    pub fn get_heap_handle() -> intptr_t;
     #[link_name = "_putenv"]
     pub fn putenv(envstring: *const c_char) -> c_int;
     #[link_name = "_wputenv"]
@@ -0,0 +1,20 @@
+// Version cfgs shared between `build.rs` and `libc-test/build.rs`, which both `include!` this
+// file. Every cfg listed here must also be in `ALLOWED_CFGS` in `build.rs`.
+
+/// glibc minor versions that introduced gated symbols, with the cfg enabled from that version on.
+const GLIBC_VERSION_CFGS: &[(u32, &str)] = &[
+    (29, "libc_glibc_2_29"),
+    (30, "libc_glibc_2_30"),
+    (31, "libc_glibc_2_31"),
+    (32, "libc_glibc_2_32"),
+    (34, "libc_glibc_2_34"),
+    (35, "libc_glibc_2_35"),
+];
+
+/// Android API levels that introduced gated bionic symbols, with the cfg enabled from that level on.
+const ANDROID_API_CFGS: &[(u32, &str)] = &[
+    (30, "libc_android_api_30"),
+    (31, "libc_android_api_31"),
+    (34, "libc_android_api_34"),
+    (35, "libc_android_api_35"),
+];